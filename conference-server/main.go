@@ -2,10 +2,14 @@ package main
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"io"
 	"log"
 	"net"
+	"os"
+	"strconv"
+	"strings"
 	"sync"
 	"time"
 
@@ -28,25 +32,36 @@ type Client struct {
 }
 
 type Room struct {
-	id      string
-	clients *sync.Map // map[clientAddr]*Client
-	users   *sync.Map // map[senderID]*Client
+	mu        sync.Mutex // serializes joins so the uniqueness and capacity checks hold
+	id        string
+	clients   *sync.Map // map[clientAddr]*Client
+	users     *sync.Map // map[senderID]*Client
+	createdAt time.Time
 }
 
 func NewRoom(id string) *Room {
 	return &Room{
-		id:      id,
-		clients: &sync.Map{},
-		users:   &sync.Map{},
+		id:        id,
+		clients:   &sync.Map{},
+		users:     &sync.Map{},
+		createdAt: time.Now(),
 	}
 }
 
-// AddClient adds a client to the room, checking for username uniqueness.
-func (r *Room) AddClient(c *Client) error {
+var errRoomFull = errors.New("room is full")
+
+// AddClient adds a client to the room, checking for username uniqueness and,
+// when maxSize > 0, that the room is not already at capacity.
+func (r *Room) AddClient(c *Client, maxSize int) error {
+	r.mu.Lock()
+	defer r.mu.Unlock()
 	// Check if username is already taken
 	if _, ok := r.users.Load(c.id); ok {
 		return fmt.Errorf("username '%s' is already taken", c.id)
 	}
+	if maxSize > 0 && r.MemberCount() >= maxSize {
+		return fmt.Errorf("room '%s' has reached its limit of %d members: %w", r.id, maxSize, errRoomFull)
+	}
 	r.clients.Store(c.addr, c)
 	r.users.Store(c.id, c)
 	return nil
@@ -67,12 +82,45 @@ type server struct {
 	transferResponses map[string]chan *pb.FileTransferResponse
 	transferMu        sync.Mutex
 	activeTransfers   sync.Map // map[transferID]transfer (p2pTransfer or broadcastTransfer)
+
+	// Rooms where audio is not relayed, from AUDIO_DISABLED_ROOMS (comma-separated)
+	audioDisabledRooms map[string]bool
+	// Maximum members per room from MAX_ROOM_SIZE; 0 means unlimited
+	maxRoomSize int
 }
 
 func newServer() *server {
 	return &server{
-		transferResponses: make(map[string]chan *pb.FileTransferResponse),
+		transferResponses:  make(map[string]chan *pb.FileTransferResponse),
+		audioDisabledRooms: parseRoomList(os.Getenv("AUDIO_DISABLED_ROOMS")),
+		maxRoomSize:        parseRoomSize(os.Getenv("MAX_ROOM_SIZE")),
+	}
+}
+
+func parseRoomSize(value string) int {
+	if value == "" {
+		return 0
+	}
+	size, err := strconv.Atoi(value)
+	if err != nil || size < 0 {
+		log.Printf("Ignoring invalid MAX_ROOM_SIZE '%s'", value)
+		return 0
+	}
+	return size
+}
+
+func parseRoomList(value string) map[string]bool {
+	rooms := make(map[string]bool)
+	for _, room := range strings.Split(value, ",") {
+		if room = strings.TrimSpace(room); room != "" {
+			rooms[room] = true
+		}
 	}
+	return rooms
+}
+
+func (s *server) audioAllowed(roomID string) bool {
+	return !s.audioDisabledRooms[roomID]
 }
 
 // --- JoinConference: Main communication stream ---
@@ -102,8 +150,14 @@ func (s *server) JoinConference(stream pb.ConferenceService_JoinConferenceServer
 		ch:     make(chan *pb.ConferenceData, 100),
 		stream: stream,
 	}
-	if err := room.AddClient(client); err != nil {
+	if err := room.AddClient(client, s.maxRoomSize); err != nil {
 		log.Printf("Client '%s' failed to join room '%s': %v", senderID, roomID, err)
+		if errors.Is(err, errRoomFull) {
+			stream.Send(&pb.ConferenceData{
+				Payload: &pb.ConferenceData_Command{Command: &pb.Command{Type: "ROOM_FULL", Value: err.Error()}},
+			})
+			return status.Error(codes.ResourceExhausted, err.Error())
+		}
 		// Send error back to client before closing
 		stream.Send(&pb.ConferenceData{
 			Payload: &pb.ConferenceData_Command{Command: &pb.Command{Type: "ERROR", Value: err.Error()}},
@@ -162,6 +216,10 @@ func (s *server) JoinConference(stream pb.ConferenceService_JoinConferenceServer
 			log.Printf("File announcement from '%s' in room '%s' for '%s'", msg.Sender, msg.RoomId, payload.FileAnnouncement.Filename)
 			s.activeTransfers.Store(payload.FileAnnouncement.TransferId, &broadcastTransfer{})
 			room.Broadcast(msg, client.addr)
+		case *pb.ConferenceData_AudioChunk:
+			if s.audioAllowed(room.id) {
+				room.Broadcast(msg, client.addr)
+			}
 		default:
 			room.Broadcast(msg, client.addr)
 		}
@@ -193,6 +251,10 @@ func (r *Room) Broadcast(msg *pb.ConferenceData, senderAddr string) {
 
 func (s *server) handlePrivateMessage(room *Room, sender *Client, pm *pb.PrivateMessage) {
 	recipientID := pm.RecipientId
+	if pm.Kind == "NUDGE" {
+		s.relayNudge(room, sender, recipientID)
+		return
+	}
 	if val, ok := room.users.Load(recipientID); ok {
 		recipient := val.(*Client)
 		
@@ -225,6 +287,38 @@ func (s *server) handlePrivateMessage(room *Room, sender *Client, pm *pb.Private
 	}
 }
 
+// relayNudge delivers a /nudge as a NUDGE command to the recipient alone. An unknown
+// recipient gets the sender a NUDGE_FAILED reply, which unlike ERROR does not end the
+// session. Both sends drop on a full channel, like Broadcast, so a slow client cannot
+// stall the sender's handler.
+func (s *server) relayNudge(room *Room, sender *Client, recipientID string) {
+	val, ok := room.users.Load(recipientID)
+	if !ok {
+		select {
+		case sender.ch <- &pb.ConferenceData{
+			Sender:  "Server",
+			Payload: &pb.ConferenceData_Command{Command: &pb.Command{Type: "NUDGE_FAILED", Value: recipientID}},
+		}:
+		default:
+		}
+		log.Printf("Failed to relay nudge from '%s': user '%s' not found.", sender.id, recipientID)
+		return
+	}
+	recipient := val.(*Client)
+	select {
+	case recipient.ch <- &pb.ConferenceData{
+		RoomId: room.id,
+		Sender: sender.id,
+		Payload: &pb.ConferenceData_Command{
+			Command: &pb.Command{Type: "NUDGE", Value: sender.id},
+		},
+	}:
+		log.Printf("Relayed nudge from '%s' to '%s'", sender.id, recipient.id)
+	default:
+		log.Printf("Dropped nudge for client %s, channel full.", recipient.id)
+	}
+}
+
 // --- Room Helpers ---
 func (r *Room) IsEmpty() bool {
 	count := 0
@@ -235,6 +329,36 @@ func (r *Room) IsEmpty() bool {
 	return count == 0
 }
 
+// MemberCount returns the number of clients currently in the room.
+func (r *Room) MemberCount() int {
+	count := 0
+	r.clients.Range(func(_, _ interface{}) bool {
+		count++
+		return true
+	})
+	return count
+}
+
+// --- Room Info ---
+
+func (s *server) GetRoomInfo(ctx context.Context, req *pb.RoomInfoRequest) (*pb.RoomInfo, error) {
+	val, ok := s.rooms.Load(req.RoomId)
+	if !ok {
+		return nil, status.Errorf(codes.NotFound, "room '%s' not found", req.RoomId)
+	}
+	room := val.(*Room)
+	return &pb.RoomInfo{
+		RoomId:           room.id,
+		MemberCount:      int32(room.MemberCount()),
+		CreatedAt:        room.createdAt.Unix(),
+		RequiresPassword: false, // Rooms are open; passwords are not supported yet
+		AudioAllowed:     s.audioAllowed(room.id),
+	}, nil
+}
+
+func (s *server) Echo(ctx context.Context, req *pb.EchoRequest) (*pb.EchoResponse, error) {
+	return &pb.EchoResponse{Payload: req.Payload, ServerTime: time.Now().UnixMilli()}, nil
+}
 
 // --- File Transfer (Unchanged from previous step, but placed here for completeness) ---
 
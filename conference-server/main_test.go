@@ -0,0 +1,116 @@
+package main
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+)
+
+func testClient(id string) *Client {
+	return &Client{id: id, addr: "127.0.0.1:" + id}
+}
+
+func TestAddClientRejectsJoinsBeyondMaxSize(t *testing.T) {
+	room := NewRoom("lobby")
+	alice, bob := testClient("alice"), testClient("bob")
+	for _, c := range []*Client{alice, bob} {
+		if err := room.AddClient(c, 2); err != nil {
+			t.Fatalf("AddClient(%s) = %v, want nil", c.id, err)
+		}
+	}
+
+	err := room.AddClient(testClient("carol"), 2)
+	if !errors.Is(err, errRoomFull) {
+		t.Fatalf("AddClient on a full room = %v, want errRoomFull", err)
+	}
+	if got := room.MemberCount(); got != 2 {
+		t.Errorf("MemberCount() = %d after a rejected join, want 2", got)
+	}
+
+	room.RemoveClient(alice)
+	if err := room.AddClient(testClient("carol"), 2); err != nil {
+		t.Errorf("AddClient after a leave = %v, want nil", err)
+	}
+}
+
+func TestAddClientWithoutLimit(t *testing.T) {
+	room := NewRoom("lobby")
+	for i := 0; i < 50; i++ {
+		if err := room.AddClient(testClient(fmt.Sprint(i)), 0); err != nil {
+			t.Fatalf("AddClient #%d with maxSize 0 = %v, want nil", i, err)
+		}
+	}
+	if got := room.MemberCount(); got != 50 {
+		t.Errorf("MemberCount() = %d, want 50", got)
+	}
+}
+
+func TestAddClientDuplicateNameIsNotRoomFull(t *testing.T) {
+	room := NewRoom("lobby")
+	if err := room.AddClient(testClient("alice"), 1); err != nil {
+		t.Fatalf("AddClient(alice) = %v, want nil", err)
+	}
+	dup := &Client{id: "alice", addr: "127.0.0.1:other"}
+	err := room.AddClient(dup, 1)
+	if err == nil || errors.Is(err, errRoomFull) {
+		t.Errorf("AddClient with a taken name = %v, want a non-capacity error", err)
+	}
+}
+
+func TestParseRoomSize(t *testing.T) {
+	tests := []struct {
+		value string
+		want  int
+	}{
+		{"", 0},
+		{"10", 10},
+		{"0", 0},
+		{"-3", 0},
+		{"many", 0},
+	}
+	for _, tt := range tests {
+		if got := parseRoomSize(tt.value); got != tt.want {
+			t.Errorf("parseRoomSize(%q) = %d, want %d", tt.value, got, tt.want)
+		}
+	}
+}
+
+func TestParseRoomList(t *testing.T) {
+	tests := []struct {
+		value string
+		want  []string
+	}{
+		{"", nil},
+		{"lobby", []string{"lobby"}},
+		{"lobby, music ,,quiet", []string{"lobby", "music", "quiet"}},
+		{" , ", nil},
+	}
+	for _, tt := range tests {
+		got := parseRoomList(tt.value)
+		if len(got) != len(tt.want) {
+			t.Errorf("parseRoomList(%q) = %v, want %v", tt.value, got, tt.want)
+			continue
+		}
+		for _, room := range tt.want {
+			if !got[room] {
+				t.Errorf("parseRoomList(%q) is missing %q", tt.value, room)
+			}
+		}
+	}
+}
+
+func TestAudioAllowedFromEnvironment(t *testing.T) {
+	t.Setenv("AUDIO_DISABLED_ROOMS", "quiet, library")
+	s := newServer()
+	if s.audioAllowed("quiet") || s.audioAllowed("library") {
+		t.Error("audio allowed in a room listed in AUDIO_DISABLED_ROOMS")
+	}
+	if !s.audioAllowed("lobby") {
+		t.Error("audio not allowed in a room missing from AUDIO_DISABLED_ROOMS")
+	}
+
+	t.Setenv("AUDIO_DISABLED_ROOMS", "")
+	if !newServer().audioAllowed("quiet") {
+		t.Error("audio not allowed with AUDIO_DISABLED_ROOMS empty")
+	}
+}
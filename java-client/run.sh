@@ -13,4 +13,4 @@ fi
 
 # Ejecutar con los flags necesarios
 java --enable-native-access=ALL-UNNAMED \
-     -jar "$JAR_FILE"
+     -jar "$JAR_FILE" "$@"
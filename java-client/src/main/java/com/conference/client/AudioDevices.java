@@ -0,0 +1,180 @@
+package com.conference.client;
+
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioSystem;
+import javax.sound.sampled.DataLine;
+import javax.sound.sampled.Line;
+import javax.sound.sampled.LineUnavailableException;
+import javax.sound.sampled.Mixer;
+import javax.sound.sampled.SourceDataLine;
+import javax.sound.sampled.TargetDataLine;
+import java.text.Normalizer;
+import java.util.ArrayList;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Set;
+
+/**
+ * Audio device lookup on top of the Java Sound mixers.
+ */
+public final class AudioDevices {
+
+    private AudioDevices() {}
+
+    /** Splits a comma-separated priority list such as "Headset,Built-in". */
+    public static List<String> parsePriorityList(String value) {
+        List<String> result = new ArrayList<>();
+        if (value == null) return result;
+        for (String item : value.split(",")) {
+            if (!item.trim().isEmpty()) result.add(item.trim());
+        }
+        return result;
+    }
+
+    /**
+     * Returns the first candidate name matching the highest-priority entry (case-insensitive
+     * substring), or null if no entry matches any candidate.
+     */
+    public static String resolvePriority(List<String> candidates, List<String> priorities) {
+        for (String wanted : priorities) {
+            String needle = wanted.toLowerCase(Locale.ROOT);
+            for (String candidate : candidates) {
+                if (candidate.toLowerCase(Locale.ROOT).contains(needle)) return candidate;
+            }
+        }
+        return null;
+    }
+
+    /** Whether any mixer offers a capture or playback line; false on headless machines and containers. */
+    public static boolean anyAvailable() {
+        return AudioSystem.getTargetLineInfo(new Line.Info(TargetDataLine.class)).length > 0
+                || AudioSystem.getSourceLineInfo(new Line.Info(SourceDataLine.class)).length > 0;
+    }
+
+    /**
+     * Candidates containing the query, ignoring case and accents ("microfono" finds
+     * "Micrófono USB"). A candidate equal to the query is returned alone.
+     */
+    public static List<String> findMatches(List<String> candidates, String query) {
+        String needle = fold(query.trim());
+        List<String> result = new ArrayList<>();
+        for (String candidate : candidates) {
+            if (fold(candidate).equals(needle)) return List.of(candidate);
+            if (fold(candidate).contains(needle)) result.add(candidate);
+        }
+        return result;
+    }
+
+    private static String fold(String text) {
+        return Normalizer.normalize(text, Normalizer.Form.NFD).replaceAll("\\p{M}", "").toLowerCase(Locale.ROOT);
+    }
+
+    /** Mixers that can open a line of the given type and format. */
+    public static List<Mixer.Info> mixersFor(Class<? extends Line> lineClass, AudioFormat format) {
+        DataLine.Info info = new DataLine.Info(lineClass, format);
+        List<Mixer.Info> result = new ArrayList<>();
+        for (Mixer.Info mixerInfo : AudioSystem.getMixerInfo()) {
+            if (AudioSystem.getMixer(mixerInfo).isLineSupported(info)) result.add(mixerInfo);
+        }
+        return result;
+    }
+
+    /** Names of the mixers that can open a line of the given type and format. */
+    public static List<String> namesFor(Class<? extends Line> lineClass, AudioFormat format) {
+        List<String> names = new ArrayList<>();
+        for (Mixer.Info mixer : mixersFor(lineClass, format)) names.add(mixer.getName());
+        return names;
+    }
+
+    /** Picks a mixer by priority list; null means "use the system default". */
+    public static Mixer.Info select(Class<? extends Line> lineClass, AudioFormat format, List<String> priorities) {
+        if (priorities.isEmpty()) return null;
+        List<Mixer.Info> mixers = mixersFor(lineClass, format);
+        List<String> names = new ArrayList<>();
+        for (Mixer.Info mixer : mixers) names.add(mixer.getName());
+        String chosen = resolvePriority(names, priorities);
+        if (chosen == null) return null;
+        return mixers.get(names.indexOf(chosen));
+    }
+
+    /**
+     * Opens a line of the given type on the first device that accepts the format: the
+     * preferred mixer (null = system default), then the system default, then every other
+     * mixer reporting support for the format. Throws the last failure if none opens.
+     */
+    public static DataLine openFirst(Class<? extends DataLine> lineClass, AudioFormat format, Mixer.Info preferred)
+            throws LineUnavailableException {
+        List<Mixer.Info> candidates = new ArrayList<>();
+        candidates.add(preferred);
+        if (preferred != null) candidates.add(null);
+        for (Mixer.Info mixer : mixersFor(lineClass, format)) {
+            if (!candidates.contains(mixer)) candidates.add(mixer);
+        }
+        LineUnavailableException last = new LineUnavailableException(I18n.t("device.no_format", format));
+        for (Mixer.Info mixer : candidates) {
+            try {
+                if (lineClass == TargetDataLine.class) {
+                    TargetDataLine line = AudioSystem.getTargetDataLine(format, mixer);
+                    line.open(format);
+                    return line;
+                }
+                SourceDataLine line = AudioSystem.getSourceDataLine(format, mixer);
+                line.open(format);
+                return line;
+            } catch (LineUnavailableException e) {
+                last = e;
+            } catch (IllegalArgumentException | SecurityException e) {
+                last = new LineUnavailableException(describe(mixer) + ": " + e.getMessage());
+            }
+        }
+        throw last;
+    }
+
+    /**
+     * Formats a line on the given mixer (null = system default) reports as supported,
+     * without opening it. Empty if the device offers no such line.
+     */
+    public static List<AudioFormat> supportedFormats(Class<? extends DataLine> lineClass, AudioFormat format, Mixer.Info mixer) {
+        List<AudioFormat> result = new ArrayList<>();
+        try {
+            DataLine line = lineClass == TargetDataLine.class
+                    ? AudioSystem.getTargetDataLine(format, mixer)
+                    : AudioSystem.getSourceDataLine(format, mixer);
+            Line.Info info = line.getLineInfo();
+            if (info instanceof DataLine.Info) {
+                for (AudioFormat supported : ((DataLine.Info) info).getFormats()) result.add(supported);
+            }
+        } catch (LineUnavailableException | IllegalArgumentException e) {
+            // No line of this kind for the device; reported as an empty list
+        }
+        return result;
+    }
+
+    /** Readable table of supported formats, ending with whether the client's format works as-is. */
+    static String formatCapabilities(String title, String device, List<AudioFormat> formats, AudioFormat wanted) {
+        StringBuilder sb = new StringBuilder(title).append(": ").append(device).append('\n');
+        if (formats.isEmpty()) {
+            return sb.append(I18n.t("caps.no_formats")).toString();
+        }
+        sb.append(String.format("  %-12s %-5s %-8s %-12s %s", I18n.t("caps.encoding"), I18n.t("caps.bits"),
+                I18n.t("caps.channels"), I18n.t("caps.rate"), I18n.t("caps.order"))).append('\n');
+        Set<String> rows = new LinkedHashSet<>();
+        boolean achievable = false;
+        for (AudioFormat format : formats) {
+            String rate = format.getSampleRate() == AudioSystem.NOT_SPECIFIED ? I18n.t("caps.any_rate") : String.format("%.0f Hz", format.getSampleRate());
+            String channels = format.getChannels() == AudioSystem.NOT_SPECIFIED ? I18n.t("caps.any_channels") : Integer.toString(format.getChannels());
+            String order = format.getSampleSizeInBits() <= 8 ? "-" : format.isBigEndian() ? "big" : "little";
+            rows.add(String.format("  %-12s %-5d %-8s %-12s %s", format.getEncoding(), format.getSampleSizeInBits(), channels, rate, order));
+            achievable |= wanted.matches(format); // NOT_SPECIFIED fields in the device format act as wildcards
+        }
+        for (String row : rows) sb.append(row).append('\n');
+        sb.append(I18n.t("caps.client_format", wanted.getSampleRate(), wanted.getSampleSizeInBits(), wanted.getChannels(),
+                I18n.t(achievable ? "caps.supported" : "caps.unsupported")));
+        return sb.toString();
+    }
+
+    public static String describe(Mixer.Info mixer) {
+        return mixer == null ? I18n.t("device.system_default") : mixer.getName();
+    }
+}
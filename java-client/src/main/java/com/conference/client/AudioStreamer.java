@@ -7,6 +7,8 @@ import io.grpc.stub.StreamObserver;
 
 import javax.sound.sampled.*;
 import java.time.Instant;
+import java.util.ArrayList;
+import java.util.List;
 import java.util.UUID;
 
 public class AudioStreamer {
@@ -14,6 +16,9 @@ public class AudioStreamer {
     private final StreamObserver<ConferenceData> requestObserver;
     private final String sender;
     private final String roomId;
+    private final SessionStats stats;
+    private volatile List<String> micPriority;
+    private volatile List<String> speakerPriority;
 
     private AudioFormat audioFormat;
     private TargetDataLine microphone;
@@ -21,55 +26,186 @@ public class AudioStreamer {
 
     private volatile boolean audioActive = false;
     private volatile boolean speakersActive = false;
+    private volatile boolean transmitting = false; // False while the mic is open only for /monitor
+    private volatile boolean monitoring = false;
+    private volatile double monitorGain = 1.0;
+    private volatile DelayLine monitorDelay = new DelayLine(0);
+    private volatile double micGain = 1.0; // From MicCalibration, applied to everything captured
+    private volatile double silenceThreshold = 0; // From MicCalibration; quieter chunks are not sent
+    private int quietChunks = 0; // Consecutive chunks below the threshold, capture thread only
+    private final int monitorDelayMs;
+    private final Object speakerLock = new Object();
+    private final Object sendLock = new Object(); // Capture and /tone threads share the stream
     private Thread micCaptureThread;
+    private Thread toneThread;
+    private final GainNormalizer gainNormalizer = new GainNormalizer();
 
-    public AudioStreamer(StreamObserver<ConferenceData> requestObserver, String sender, String roomId) {
+    static final int SAMPLE_RATE = 44100;
+    private static final int CHUNK_SIZE = 1024;
+    private static final int MIN_TONE_HZ = 50;
+    private static final int MAX_TONE_HZ = 4000;
+    private static final double MAX_TONE_SECONDS = 5.0;
+    private static final long THREAD_JOIN_TIMEOUT_MS = 1000;
+    private static final int SILENCE_HANGOVER_CHUNKS = 30; // ~350 ms, so word endings are not cut
+
+    public AudioStreamer(StreamObserver<ConferenceData> requestObserver, String sender, String roomId, ClientOptions options, SessionStats stats) {
         this.requestObserver = requestObserver;
+        this.stats = stats;
         this.sender = sender;
         this.roomId = roomId;
-        this.audioFormat = new AudioFormat(44100, 16, 1, true, false); // 44.1kHz, 16bit, Mono, Signed, Little-endian
+        this.audioFormat = createFormat(options.getAudioChannels());
+        this.micPriority = AudioDevices.parsePriorityList(options.getMicPriority());
+        this.speakerPriority = AudioDevices.parsePriorityList(options.getSpeakerPriority());
+        this.monitorDelayMs = options.getMonitorDelayMs();
+    }
+
+    private static AudioFormat createFormat(int channels) {
+        return new AudioFormat(SAMPLE_RATE, 16, channels, true, false); // 44.1kHz, 16bit, Signed, Little-endian
     }
 
     public void startAudio() {
+        if (audioActive && !transmitting) {
+            transmitting = true;
+            System.out.println(I18n.t("audio.transmitting"));
+            return;
+        }
         if (audioActive) {
-            System.out.println("El audio ya está activo.");
+            System.out.println(I18n.t("audio.already_active"));
             return;
         }
+        openDevices(true);
+    }
+
+    /**
+     * Plays your own microphone locally, delayed by --monitor-delay-ms and scaled by gain.
+     * If the mic is off the devices are opened without transmitting, so nothing reaches the room.
+     */
+    public void startMonitor(double gain) {
+        monitorGain = gain;
+        if (!audioActive) {
+            openDevices(false);
+            if (!audioActive) return;
+        }
+        // The delay is held in the monitor's own buffer; writing silence into the speakers
+        // line would also hold back room audio queued behind it.
+        monitorDelay = new DelayLine((int) (audioFormat.getFrameRate() * monitorDelayMs / 1000) * audioFormat.getFrameSize());
+        monitoring = true;
+        System.out.println(I18n.t("audio.monitor_on", gain));
+    }
+
+    public void stopMonitor() {
+        if (!monitoring) return;
+        monitoring = false;
+        System.out.println(I18n.t("audio.monitor_off"));
+        if (audioActive && !transmitting) {
+            stopAudio();
+        }
+    }
+
+    public void setMicGain(double gain) {
+        micGain = gain;
+    }
+
+    /** RMS level, measured before the gain, under which captured audio is not transmitted. */
+    public void setSilenceThreshold(double rms) {
+        silenceThreshold = rms;
+    }
+
+    // Silence gate with hangover: a quiet chunk is only dropped once the quiet run is long enough.
+    private boolean isSilence(byte[] pcm, int length) {
+        if (silenceThreshold <= 0) return false;
+        if (GainNormalizer.rms(java.util.Arrays.copyOf(pcm, length)) >= silenceThreshold) {
+            quietChunks = 0;
+            return false;
+        }
+        return ++quietChunks > SILENCE_HANGOVER_CHUNKS;
+    }
+
+    /**
+     * Opens the microphone on its own for the given time and returns the RMS level of each
+     * captured chunk, before any gain. Used by calibration while audio is off.
+     */
+    public List<Double> measureMicLevels(double seconds) throws LineUnavailableException {
+        Mixer.Info micMixer = AudioDevices.select(TargetDataLine.class, audioFormat, micPriority);
+        TargetDataLine line = (TargetDataLine) AudioDevices.openFirst(TargetDataLine.class, audioFormat, micMixer);
+        List<Double> levels = new ArrayList<>();
+        try {
+            line.start();
+            byte[] buffer = new byte[CHUNK_SIZE];
+            long bytesWanted = (long) (seconds * audioFormat.getFrameRate()) * audioFormat.getFrameSize();
+            for (long total = 0; total < bytesWanted; ) {
+                int bytesRead = line.read(buffer, 0, buffer.length);
+                if (bytesRead <= 0) break;
+                levels.add(GainNormalizer.rms(java.util.Arrays.copyOf(buffer, bytesRead)));
+                total += bytesRead;
+            }
+        } finally {
+            line.stop();
+            line.close();
+        }
+        return levels;
+    }
+
+    /** Opens only the speakers, for --audio-only: room audio is played and nothing is captured. */
+    public void startListening() {
+        if (speakersActive) return;
+        try {
+            if (audioFormat.getChannels() == 2 && !AudioSystem.isLineSupported(new DataLine.Info(SourceDataLine.class, audioFormat))) {
+                System.out.println(I18n.t("audio.mono_fallback"));
+                audioFormat = createFormat(1);
+            }
+            Mixer.Info speakerMixer = AudioDevices.select(SourceDataLine.class, audioFormat, speakerPriority);
+            speakers = (SourceDataLine) AudioDevices.openFirst(SourceDataLine.class, audioFormat, speakerMixer);
+            speakers.start();
+            speakersActive = true;
+            System.out.println(I18n.t("audio.listening"));
+        } catch (LineUnavailableException e) {
+            System.err.println(I18n.t("audio.device_error", e.getMessage()));
+        }
+    }
+
+    private void openDevices(boolean transmit) {
         try {
-            // Init microphone
-            DataLine.Info micInfo = new DataLine.Info(TargetDataLine.class, audioFormat);
-            microphone = (TargetDataLine) AudioSystem.getLine(micInfo);
-            microphone.open(audioFormat);
+            if (audioFormat.getChannels() == 2 && !isFormatSupported(audioFormat)) {
+                System.out.println(I18n.t("audio.mono_fallback"));
+                audioFormat = createFormat(1);
+            }
+
+            // Init microphone (a null mixer means the system default); if the preferred
+            // device refuses the format, any other device that accepts it is used instead.
+            Mixer.Info micMixer = AudioDevices.select(TargetDataLine.class, audioFormat, micPriority);
+            microphone = (TargetDataLine) AudioDevices.openFirst(TargetDataLine.class, audioFormat, micMixer);
             microphone.start();
 
             // Init speakers
-            DataLine.Info speakerInfo = new DataLine.Info(SourceDataLine.class, audioFormat);
-            speakers = (SourceDataLine) AudioSystem.getLine(speakerInfo);
-            speakers.open(audioFormat);
+            Mixer.Info speakerMixer = AudioDevices.select(SourceDataLine.class, audioFormat, speakerPriority);
+            speakers = (SourceDataLine) AudioDevices.openFirst(SourceDataLine.class, audioFormat, speakerMixer);
             speakers.start();
+            if (!micPriority.isEmpty() || !speakerPriority.isEmpty()) {
+                System.out.println(I18n.t("audio.devices", AudioDevices.describe(micMixer), AudioDevices.describe(speakerMixer)));
+            }
             
             audioActive = true;
             speakersActive = true;
-            System.out.println("🎤 Micrófono y altavoces activados.");
+            transmitting = transmit;
+            System.out.println(I18n.t(transmit ? "audio.on" : "audio.monitor_only"));
 
             // Start thread to capture and send audio
             micCaptureThread = new Thread(() -> {
-                byte[] buffer = new byte[1024];
+                byte[] buffer = new byte[CHUNK_SIZE];
                 while (audioActive) {
                     int bytesRead = microphone.read(buffer, 0, buffer.length);
                     if (bytesRead > 0) {
+                        boolean silence = isSilence(buffer, bytesRead);
+                        byte[] captured = micGain == 1.0 ? buffer : GainNormalizer.applyGain(buffer, micGain);
+                        if (monitoring) {
+                            playLocal(monitorDelay.process(GainNormalizer.applyGain(java.util.Arrays.copyOf(captured, bytesRead), monitorGain)));
+                        }
+                        if (!transmitting || silence) continue;
                         try {
-                            AudioChunk audioChunk = AudioChunk.newBuilder()
-                                    .setData(ByteString.copyFrom(buffer, 0, bytesRead))
-                                    .build();
-                            ConferenceData conferenceData = ConferenceData.newBuilder()
-                                    .setSender(sender)
-                                    .setRoomId(roomId)
-                                    .setAudioChunk(audioChunk)
-                                    .build();
-                            requestObserver.onNext(conferenceData);
+                            sendAudioChunk(captured, bytesRead);
                         } catch (Exception e) {
-                            System.err.println("Error al enviar audio: " + e.getMessage());
+                            System.err.println(I18n.t("audio.send_error", e.getMessage()));
                             audioActive = false;
                         }
                     }
@@ -79,43 +215,260 @@ public class AudioStreamer {
             micCaptureThread.start();
 
         } catch (LineUnavailableException e) {
-            System.err.println("Error al acceder a dispositivo de audio: " + e.getMessage());
+            System.err.println(I18n.t("audio.device_error", e.getMessage()));
+            // A mic opened before the speakers failed must not stay held
+            if (microphone != null && microphone.isOpen()) microphone.close();
             audioActive = false;
+            speakersActive = false;
         }
     }
 
-    public void stopAudio() {
-        if (!audioActive) {
+    private static boolean isFormatSupported(AudioFormat format) {
+        return AudioSystem.isLineSupported(new DataLine.Info(TargetDataLine.class, format))
+                && AudioSystem.isLineSupported(new DataLine.Info(SourceDataLine.class, format));
+    }
+
+    private void sendAudioChunk(byte[] buffer, int length) {
+        AudioChunk audioChunk = AudioChunk.newBuilder()
+                .setData(ByteString.copyFrom(buffer, 0, length))
+                .setChannels(audioFormat.getChannels())
+                .build();
+        ConferenceData conferenceData = ConferenceData.newBuilder()
+                .setSender(sender)
+                .setRoomId(roomId)
+                .setAudioChunk(audioChunk)
+                .build();
+        // StreamObserver is not thread-safe: a tone may be sent while the mic is capturing
+        synchronized (sendLock) {
+            requestObserver.onNext(conferenceData);
+        }
+        stats.audioSent(length, audioFormat.getChannels());
+    }
+
+    /**
+     * Sends a synthetic sine tone through the same path as the microphone, paced in real time
+     * and scaled by the same calibrated gain. Frequency and duration are clamped to safe ranges.
+     */
+    public void sendTone(int frequencyHz, double seconds) {
+        if (toneThread != null && toneThread.isAlive()) {
+            System.out.println(I18n.t("tone.busy"));
             return;
         }
+        int hz = Math.max(MIN_TONE_HZ, Math.min(MAX_TONE_HZ, frequencyHz));
+        double duration = Math.max(0.1, Math.min(MAX_TONE_SECONDS, seconds));
+        byte[] generated = convertChannels(generateTone(hz, duration, audioFormat.getSampleRate()), 1, audioFormat.getChannels());
+        byte[] tone = micGain == 1.0 ? generated : GainNormalizer.applyGain(generated, micGain);
+        System.out.println(I18n.t("tone.sending", hz, duration));
+
+        toneThread = new Thread(() -> {
+            int bytesPerSecond = (int) audioFormat.getSampleRate() * audioFormat.getFrameSize();
+            long chunkMillis = CHUNK_SIZE * 1000L / bytesPerSecond;
+            try {
+                for (int offset = 0; offset < tone.length; offset += CHUNK_SIZE) {
+                    int length = Math.min(CHUNK_SIZE, tone.length - offset);
+                    sendAudioChunk(java.util.Arrays.copyOfRange(tone, offset, offset + length), length);
+                    Thread.sleep(chunkMillis);
+                }
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            } catch (Exception e) {
+                System.err.println(I18n.t("tone.error", e.getMessage()));
+            }
+        });
+        toneThread.setDaemon(true);
+        toneThread.start();
+    }
+
+    // Generates a 16-bit signed little-endian mono sine wave at half amplitude.
+    static byte[] generateTone(int frequencyHz, double seconds, float sampleRate) {
+        int samples = (int) (seconds * sampleRate);
+        byte[] data = new byte[samples * 2];
+        for (int i = 0; i < samples; i++) {
+            short value = (short) (Math.sin(2 * Math.PI * frequencyHz * i / sampleRate) * Short.MAX_VALUE / 2);
+            data[2 * i] = (byte) value;
+            data[2 * i + 1] = (byte) (value >> 8);
+        }
+        return data;
+    }
+
+    public void stopAudio() {
+        if (releaseDevices()) {
+            System.out.println(I18n.t("audio.off"));
+        }
+    }
+
+    // Stops capture, playback and monitor; true if the mic was open.
+    private boolean releaseDevices() {
+        boolean wasActive = audioActive;
         audioActive = false;
         speakersActive = false;
+        transmitting = false;
+        monitoring = false;
 
-        if (micCaptureThread != null) {
-            micCaptureThread.interrupt();
-        }
+        // The capture thread may already have stopped itself after a send error, leaving the
+        // lines open, so release them regardless of the previous state.
         if (microphone != null && microphone.isOpen()) {
             microphone.stop();
-            microphone.close();
+            microphone.close(); // Unblocks a pending read so the capture thread can exit
         }
+        joinQuietly(micCaptureThread);
         if (speakers != null && speakers.isOpen()) {
             speakers.drain();
             speakers.close();
         }
-        System.out.println("🎤 Micrófono y altavoces desactivados.");
+        return wasActive;
+    }
+
+    /**
+     * Stops every audio thread (capture and test tone) and releases the devices. Called
+     * whenever a session ends so nothing keeps writing into a finished stream.
+     */
+    public void shutdown() {
+        shutdown(true);
+    }
+
+    /**
+     * Like shutdown(), but with announce false the "off" notice is left out so the caller
+     * can report an interruption instead. Returns whether the mic was open.
+     */
+    public boolean shutdown(boolean announce) {
+        joinQuietly(toneThread);
+        boolean wasActive = releaseDevices();
+        if (wasActive && announce) {
+            System.out.println(I18n.t("audio.off"));
+        }
+        return wasActive;
+    }
+
+    private static void joinQuietly(Thread thread) {
+        if (thread == null || thread == Thread.currentThread()) return;
+        thread.interrupt();
+        try {
+            thread.join(THREAD_JOIN_TIMEOUT_MS);
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+        }
     }
     
-    public void playAudioChunk(byte[] audioData) {
+    public void playAudioChunk(String fromSender, byte[] audioData, int channels) {
         if (speakersActive && speakers != null && speakers.isOpen()) {
-            speakers.write(audioData, 0, audioData.length);
+            byte[] matched = convertChannels(audioData, channels == 0 ? 1 : channels, audioFormat.getChannels());
+            byte[] normalized = gainNormalizer.process(fromSender, matched);
+            playLocal(normalized);
         }
     }
 
+    // Room audio and the monitor write from different threads; keep each chunk whole.
+    private void playLocal(byte[] pcm) {
+        synchronized (speakerLock) {
+            if (speakers != null && speakers.isOpen()) {
+                speakers.write(pcm, 0, pcm.length);
+            }
+        }
+    }
+
+    /**
+     * Whether a received chunk is safe to play: the payload fits the configured limit and
+     * the channel count is one this client can convert (0 is treated as mono).
+     */
+    static boolean isAcceptableChunk(int size, int channels, int maxBytes) {
+        return size <= maxBytes && channels >= 0 && channels <= 2;
+    }
+
+    // Upmixes mono to interleaved stereo by duplicating samples, or downmixes stereo to mono by averaging.
+    static byte[] convertChannels(byte[] pcm, int from, int to) {
+        if (from == to) return pcm;
+        if (from == 1 && to == 2) {
+            byte[] out = new byte[pcm.length * 2];
+            for (int i = 0; i + 1 < pcm.length; i += 2) {
+                out[2 * i] = pcm[i];
+                out[2 * i + 1] = pcm[i + 1];
+                out[2 * i + 2] = pcm[i];
+                out[2 * i + 3] = pcm[i + 1];
+            }
+            return out;
+        }
+        if (from == 2 && to == 1) {
+            byte[] out = new byte[pcm.length / 2];
+            for (int i = 0; i + 3 < pcm.length; i += 4) {
+                short left = (short) ((pcm[i] & 0xFF) | (pcm[i + 1] << 8));
+                short right = (short) ((pcm[i + 2] & 0xFF) | (pcm[i + 3] << 8));
+                int mixed = (left + right) / 2;
+                out[i / 2] = (byte) mixed;
+                out[i / 2 + 1] = (byte) (mixed >> 8);
+            }
+            return out;
+        }
+        return pcm;
+    }
+
+    /** Capabilities of the input and output devices audio would use, for /test-audio. */
+    public String describeDevices() {
+        Mixer.Info micMixer = AudioDevices.select(TargetDataLine.class, audioFormat, micPriority);
+        Mixer.Info speakerMixer = AudioDevices.select(SourceDataLine.class, audioFormat, speakerPriority);
+        return I18n.t("caps.title") + "\n"
+                + AudioDevices.formatCapabilities(I18n.t("caps.mic"), AudioDevices.describe(micMixer),
+                        AudioDevices.supportedFormats(TargetDataLine.class, audioFormat, micMixer), audioFormat) + "\n"
+                + AudioDevices.formatCapabilities(I18n.t("caps.speakers"), AudioDevices.describe(speakerMixer),
+                        AudioDevices.supportedFormats(SourceDataLine.class, audioFormat, speakerMixer), audioFormat);
+    }
+
+    public List<String> inputDeviceNames() {
+        return AudioDevices.namesFor(TargetDataLine.class, audioFormat);
+    }
+
+    public List<String> outputDeviceNames() {
+        return AudioDevices.namesFor(SourceDataLine.class, audioFormat);
+    }
+
+    /** Uses this microphone instead of --mic-priority from the next time the mic is opened. */
+    public void setMicDevice(String name) {
+        micPriority = List.of(name);
+    }
+
+    /** Uses these speakers instead of --speaker-priority from the next time they are opened. */
+    public void setSpeakerDevice(String name) {
+        speakerPriority = List.of(name);
+    }
+
+    public void forgetSender(String fromSender) {
+        gainNormalizer.forget(fromSender);
+    }
+
+    /** True while the mic is transmitting to the room (not when it is open only for /monitor). */
     public boolean isAudioActive() {
-        return audioActive;
+        return audioActive && transmitting;
+    }
+
+    public boolean isMonitoring() {
+        return monitoring;
     }
     
     public boolean isSpeakersActive() {
         return speakersActive;
     }
+
+    /**
+     * Fixed delay for the monitor: each chunk comes out as long as it went in, made of the
+     * bytes captured delaySize bytes earlier (silence at first). Used by the capture thread only.
+     */
+    static final class DelayLine {
+        private final byte[] ring;
+        private int position = 0;
+
+        DelayLine(int delaySize) {
+            this.ring = new byte[delaySize];
+        }
+
+        byte[] process(byte[] pcm) {
+            if (ring.length == 0) return pcm;
+            byte[] out = new byte[pcm.length];
+            for (int i = 0; i < pcm.length; i++) {
+                out[i] = ring[position];
+                ring[position] = pcm[i];
+                position = (position + 1) % ring.length;
+            }
+            return out;
+        }
+    }
 }
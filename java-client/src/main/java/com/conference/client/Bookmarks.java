@@ -0,0 +1,61 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.time.Clock;
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Messages saved with /save, stored as a JSON array in bookmarks.json.
+ */
+public class Bookmarks {
+
+    public static final String DEFAULT_FILE = "bookmarks.json";
+
+    private final Path path;
+    private final Clock clock;
+
+    public Bookmarks(Path path, Clock clock) {
+        this.path = path;
+        this.clock = clock;
+    }
+
+    public Bookmarks(Path path) {
+        this(path, Clock.systemDefaultZone());
+    }
+
+    public Bookmarks() {
+        this(Paths.get(DEFAULT_FILE));
+    }
+
+    public synchronized void save(MessageHistory.Entry entry, String roomId, String note) throws IOException {
+        List<Map<String, String>> all = list();
+        Map<String, String> bookmark = new LinkedHashMap<>();
+        bookmark.put("room_id", roomId);
+        bookmark.put("sender", entry.getSender());
+        bookmark.put("content", entry.getContent());
+        bookmark.put("timestamp", Long.toString(entry.getTimestamp()));
+        bookmark.put("trace_id", entry.getTraceId());
+        bookmark.put("note", note);
+        bookmark.put("saved_at", Long.toString(clock.instant().getEpochSecond()));
+        all.add(bookmark);
+        Files.write(path, SimpleJson.array(all).getBytes(StandardCharsets.UTF_8));
+    }
+
+    public synchronized List<Map<String, String>> list() throws IOException {
+        if (!Files.exists(path)) {
+            return new ArrayList<>();
+        }
+        try {
+            return SimpleJson.parseArray(new String(Files.readAllBytes(path), StandardCharsets.UTF_8));
+        } catch (IllegalArgumentException e) {
+            throw new IOException("formato inválido en " + path + ": " + e.getMessage());
+        }
+    }
+}
@@ -3,17 +3,38 @@ package com.conference.client;
 import com.conference.grpc.*;
 import io.grpc.ManagedChannel;
 import io.grpc.ManagedChannelBuilder;
+import io.grpc.Status;
 import io.grpc.stub.StreamObserver;
 
+import javax.sound.sampled.LineUnavailableException;
+import java.io.IOException;
+import java.net.Inet6Address;
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.nio.file.Paths;
+import java.time.Clock;
 import java.time.Instant;
 import java.time.LocalDateTime;
 import java.time.ZoneId;
 import java.time.format.DateTimeFormatter;
-import java.util.Scanner;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+import java.util.StringJoiner;
+import java.util.TreeSet;
 import java.util.UUID;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.CopyOnWriteArrayList;
 import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.Executors;
+import java.util.concurrent.ScheduledExecutorService;
 import java.util.concurrent.TimeUnit;
 import java.util.concurrent.atomic.AtomicBoolean;
+import java.util.function.Consumer;
+import java.util.logging.Logger;
 
 public class ChatClient {
 
@@ -21,10 +42,19 @@ public class ChatClient {
     public enum SessionResult {
         NORMAL_LEAVE,
         QUIT_APPLICATION,
-        CONNECTION_ERROR
+        CONNECTION_ERROR,
+        RECONNECT,
+        ROOM_FULL
     }
 
+    public static final int EXIT_ROOM_FULL = 3;
+
     private final ManagedChannel channel;
+    private final Clock clock;
+    private final String host;
+    private final int port;
+    private final ClientOptions options;
+    private final ConsoleInput input;
     private final ConferenceServiceGrpc.ConferenceServiceStub asyncStub;
     private String sender;
     private String roomId;
@@ -33,35 +63,142 @@ public class ChatClient {
     private StreamObserver<ConferenceData> requestObserver;
     private CountDownLatch finishLatch;
     private SessionResult sessionResult;
+    private boolean restoreAudioOnJoin = false;
+    private volatile boolean audioAllowed = false;
+    private volatile CountDownLatch audioPolicyLatch = new CountDownLatch(1); // Released once the policy is known
+    private final boolean audioDevices; // False without any sound device: audio stays off, chat works
+    private volatile boolean serverUnavailable = false;
+    private boolean everConnected = false;
+    private String pendingQuote; // Set by /quote, prepended to the next message typed
+    // Shared by every trace id this run sends, so one client's messages can be grepped in server logs.
+    private final String tracePrefix = UUID.randomUUID().toString().substring(0, 4);
+    private final Set<String> recentTraceIds;
+    private final MessageHistory history;
+    private final MentionAlert mentionAlert;
+    private final MentionAlert messageBell; // null with --bell-on-message off
+    private final SessionStats stats = new SessionStats();
+    private final Bookmarks bookmarks;
+    private final RosterCache roster; // null unless --roster-cache
+    private final MicCalibration micCalibration = new MicCalibration();
+    private Double micGain; // null until the mic has been calibrated
+    private Double silenceThreshold; // null until calibrated; silence is then not transmitted
+    private boolean calibrationTried = false; // Automatic calibration runs at most once per run
+    private String micDevice; // Chosen with /find-device, kept across rooms; null = --mic-priority
+    private String speakerDevice;
+    private final Set<String> silenced = ConcurrentHashMap.newKeySet(); // Audio dropped, chat still shown
+    private final Map<String, Long> lastNudge = new ConcurrentHashMap<>(); // Sender -> epoch second of the last nudge shown
+    private ScheduledExecutorService statsScheduler;
+    private long lastVuMeterNanos = 0;
+    private final List<ClientEventListener> listeners = new CopyOnWriteArrayList<>();
+    private final List<MessageTransform> transforms = new CopyOnWriteArrayList<>();
+    private static final Logger LOGGER = Logger.getLogger(ChatClient.class.getName());
 
 
+    private static final long MAX_WAIT_BACKOFF_SECONDS = 30;
+    static final int MAX_DISPLAY_NAME = 32;
+    private static final long MIN_VALID_TIMESTAMP = 946684800L; // 2000-01-01
+    private static final long MAX_CLOCK_SKEW_SECONDS = 24 * 3600;
+    private static final long AUDIO_POLICY_WAIT_SECONDS = 3;
+    static final long NUDGE_COOLDOWN_SECONDS = 30;
+    private static final double MAX_MONITOR_GAIN = 4.0;
+    static final Set<String> COMMANDS = Set.of(
+            "/help", "/quit", "/exit", "/leave", "/reconnect", "/multiline", "/msg", "/mic", "/monitor", "/test-audio", "/find-device", "/tone", "/reply",
+            "/quote", "/stats", "/save", "/bookmarks", "/mentions", "/roominfo", "/invite", "/echo", "/whoami", "/export",
+            "/silence", "/unsilence", "/silenced", "/nudge", "/scroll",
+            "/upload", "/upload-all", "/download", "/accept", "/reject");
+    // Commands that put text, audio or files into the room; refused with --read-only.
+    private static final Set<String> SENDING_COMMANDS = Set.of(
+            "/msg", "/nudge", "/multiline", "/quote", "/reply", "/mic", "/tone", "/upload", "/upload-all", "/accept");
+    private static final Set<String> AUDIO_ONLY_COMMANDS = Set.of("/quit", "/exit", "/leave");
+    private static final long VU_METER_INTERVAL_NANOS = TimeUnit.MILLISECONDS.toNanos(200);
+    static final int VU_METER_WIDTH = 20;
     private static final DateTimeFormatter TIME_FORMATTER = DateTimeFormatter.ofPattern("HH:mm");
 
-    public ChatClient(String host, int port) {
+    public ChatClient(String host, int port, ClientOptions options, ConsoleInput input) {
+        this(host, port, options, input, Clock.systemDefaultZone());
+    }
+
+    /**
+     * The clock supplies every wall-clock reading: message timestamps, the prompt and
+     * received-message times, bookmark dates and alert throttling. Durations (join
+     * time, /echo, time connected) use System.nanoTime and are not affected by it.
+     */
+    public ChatClient(String host, int port, ClientOptions options, ConsoleInput input, Clock clock) {
+        this.host = host;
+        this.port = port;
+        this.options = options;
+        this.input = input;
+        this.clock = clock;
+        this.history = new MessageHistory(options.getScrollback());
+        this.bookmarks = new Bookmarks(Paths.get(Bookmarks.DEFAULT_FILE), clock);
+        this.mentionAlert = options.isAlert() ? new MentionAlert(options.getMentionSound(), clock) : null;
+        this.messageBell = options.getBellMode() != MentionAlert.BellMode.OFF ? new MentionAlert(null, clock) : null;
+        this.roster = options.isRosterCache() ? loadRoster(clock) : null;
+        this.micGain = micCalibration.load();
+        this.silenceThreshold = micCalibration.loadThreshold();
+        this.audioDevices = AudioDevices.anyAvailable();
+        if (options.getStatsIntervalSeconds() > 0) {
+            startStatsLogging(options.getStatsIntervalSeconds());
+        }
+        this.recentTraceIds = Collections.newSetFromMap(new LinkedHashMap<String, Boolean>() {
+            @Override
+            protected boolean removeEldestEntry(Map.Entry<String, Boolean> eldest) {
+                return size() > options.getDedupeWindow();
+            }
+        });
         this.channel = ManagedChannelBuilder.forAddress(host, port)
                 .usePlaintext()
                 .defaultLoadBalancingPolicy("pick_first")
+                .maxInboundMessageSize(options.getMaxRecvSize())
                 .build();
         this.asyncStub = ConferenceServiceGrpc.newStub(channel);
     }
 
-    private synchronized void printMessage(String message) {
-        System.out.print("\r\u001b[2K");
-        System.out.println(message);
+    public void addEventListener(ClientEventListener listener) {
+        listeners.add(listener);
     }
 
-    private synchronized void printPrompt() {
-        System.out.print("[" + LocalDateTime.now().format(TIME_FORMATTER) + "] " + this.sender + ": ");
-        System.out.flush();
+    public void removeEventListener(ClientEventListener listener) {
+        listeners.remove(listener);
+    }
+
+    public void addMessageTransform(MessageTransform transform) {
+        transforms.add(transform);
+    }
+
+    public void removeMessageTransform(MessageTransform transform) {
+        transforms.remove(transform);
+    }
+
+    private String transformOutgoing(String text) {
+        for (MessageTransform transform : transforms) {
+            text = transform.transform(text);
+        }
+        return text;
+    }
+
+    private void printMessage(String message) {
+        ConsoleOutput.printLine(message);
+    }
+
+    private void printPrompt() {
+        if (options.isAudioOnly()) return;
+        ConsoleOutput.printPrompt("[" + LocalDateTime.now(clock).format(TIME_FORMATTER) + "] " + this.sender + ": ");
     }
 
     public void shutdown() {
+        // Audio first so no capture thread writes into the stream after it is completed.
+        if (audioStreamer != null) {
+            boolean transmitting = audioStreamer.isAudioActive();
+            audioStreamer.shutdown();
+            reportMicChange(transmitting);
+        }
+        if (statsScheduler != null) {
+            statsScheduler.shutdownNow();
+        }
         if (requestObserver != null) {
             try { requestObserver.onCompleted(); } catch (Exception e) { /* Ignore */ }
         }
-        if (audioStreamer != null && audioStreamer.isAudioActive()) {
-            audioStreamer.stopAudio();
-        }
         try {
             channel.shutdown().awaitTermination(5, TimeUnit.SECONDS);
         } catch (InterruptedException e) {
@@ -70,61 +207,214 @@ public class ChatClient {
     }
 
     public SessionResult startChat(String sender, String roomId) throws InterruptedException {
+        beginSession(sender, roomId);
+        final AtomicBoolean connectionSuccessful = new AtomicBoolean(false);
+        // Released as soon as the server answers the join, whether it accepts it or not.
+        final CountDownLatch joinLatch = new CountDownLatch(1);
+        StreamObserver<ConferenceData> responseObserver = newResponseObserver(connectionSuccessful, joinLatch);
+
+        if (options.isDumpProto()) {
+            // stderr, like --verbose, so the trace stays out of the chat view
+            requestObserver = new ProtoDump(">>", asyncStub.joinConference(new ProtoDump("<<", responseObserver, System.err)), System.err);
+        } else {
+            requestObserver = asyncStub.joinConference(responseObserver);
+        }
+        this.audioStreamer = new AudioStreamer(requestObserver, sender, roomId, options, stats);
+        if (micGain != null) audioStreamer.setMicGain(micGain);
+        if (silenceThreshold != null) audioStreamer.setSilenceThreshold(silenceThreshold);
+        if (micDevice != null) audioStreamer.setMicDevice(micDevice);
+        if (speakerDevice != null) audioStreamer.setSpeakerDevice(speakerDevice);
+        this.fileTransferManager = new FileTransferManager(asyncStub, requestObserver, sender, clock);
+
+        try {
+            ConferenceData joinMessage = ConferenceData.newBuilder().setSender(sender).setRoomId(roomId)
+                    .setCommand(com.conference.grpc.Command.newBuilder().setType("JOIN").build()).build();
+            requestObserver.onNext(joinMessage);
+
+            // A server that accepts the connection but never answers would otherwise block here forever.
+            if (!joinLatch.await(options.getJoinTimeoutSeconds(), TimeUnit.SECONDS)) {
+                printMessage(I18n.t("join.timeout", options.getJoinTimeoutSeconds()));
+                requestObserver.onError(new RuntimeException("join timeout"));
+                return SessionResult.CONNECTION_ERROR;
+            }
+            if (!connectionSuccessful.get()) {
+                return this.sessionResult;
+            }
+            everConnected = true;
+            long connectedAt = System.nanoTime();
+            if (!options.isQuiet()) {
+                printMessage(connectionBanner());
+            }
+            fetchAudioPolicy();
+            if (options.isAudioOnly()) {
+                printMessage(I18n.t("audio_only.banner", roomId));
+                if (audioDevices) audioStreamer.startListening();
+            } else if (restoreAudioOnJoin) {
+                restoreAudioOnJoin = false;
+                String unavailable = audioUnavailable();
+                if (unavailable != null) printMessage(unavailable);
+                else setMic(true);
+            }
+
+            Thread inputThread = new Thread(this::handleUserInput);
+            inputThread.start();
+            try {
+                finishLatch.await();
+            } finally {
+                stats.connectedFor(TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - connectedAt));
+            }
+            inputThread.interrupt();
+        } catch (RuntimeException e) {
+            requestObserver.onError(e);
+            throw e;
+        } finally {
+            // Whatever ended the session, audio threads must not outlive it. A chosen stop
+            // (/leave, /quit, the server closing the room) reports audio off; a lost connection
+            // reports the interruption instead.
+            boolean lost = sessionResult == SessionResult.CONNECTION_ERROR;
+            boolean transmitting = audioStreamer.isAudioActive();
+            if (audioStreamer.shutdown(!lost) && lost) printMessage(I18n.t("audio.interrupted"));
+            reportMicChange(transmitting);
+            saveRoster();
+        }
+        return this.sessionResult;
+    }
+
+    // Per-session state, reset before each join; history survives a rejoin of the same room.
+    void beginSession(String sender, String roomId) {
+        if (!roomId.equals(this.roomId)) {
+            history.clear();
+            pendingQuote = null;
+        }
         this.sender = sender;
         this.roomId = roomId;
         this.finishLatch = new CountDownLatch(1);
         this.sessionResult = SessionResult.CONNECTION_ERROR; // Default to error
-        final AtomicBoolean connectionSuccessful = new AtomicBoolean(false);
+        this.serverUnavailable = false;
+    }
+
+    // Handles what the server sends for one session; connectionSuccessful is set on WELCOME.
+    StreamObserver<ConferenceData> newResponseObserver(AtomicBoolean connectionSuccessful, CountDownLatch joinLatch) {
+        return new StreamObserver<>() {
+            // The server announces the members before WELCOME; their events wait for onConnected.
+            private final List<Consumer<ClientEventListener>> beforeWelcome = new ArrayList<>();
+
+            private void notifyRoster(Consumer<ClientEventListener> event) {
+                if (connectionSuccessful.get()) listeners.forEach(event);
+                else beforeWelcome.add(event);
+            }
 
-        StreamObserver<ConferenceData> responseObserver = new StreamObserver<>() {
             @Override
             public void onNext(ConferenceData data) {
-                if (data.getSender().equals(ChatClient.this.sender) && data.getPayloadCase() != ConferenceData.PayloadCase.COMMAND) {
-                    return;
-                }
-
                 final boolean shouldPrintPrompt = data.getPayloadCase() != ConferenceData.PayloadCase.AUDIO_CHUNK;
 
                 switch (data.getPayloadCase()) {
                     case TEXT_MESSAGE:
                         ChatMessage chat = data.getTextMessage();
+                        // Own messages are recognised by trace id, not by name: a relayed copy of
+                        // something this session sent is dropped, another sender's is not.
+                        if (isOwnTraceId(chat.getTraceId()) || isDuplicate(chat.getTraceId())) {
+                            return;
+                        }
+                        if (options.isAudioOnly()) break;
                         if (data.getSender().equals("Sistema-FileTransfer") && chat.getContent().startsWith("FILE_REQUEST:")) {
                             handleP2PFileRequestNotification(chat.getContent());
                         } else {
-                            LocalDateTime dt = LocalDateTime.ofInstant(Instant.ofEpochSecond(chat.getTimestamp()), ZoneId.systemDefault());
+                            long timestamp = validTimestamp(chat.getTimestamp(), clock.instant().getEpochSecond());
+                            if (timestamp != chat.getTimestamp() && options.isVerbose()) {
+                                System.err.println("[verbose] invalid timestamp " + chat.getTimestamp() + " from '" + data.getSender()
+                                        + "' trace_id=" + chat.getTraceId() + ", shown with the local time");
+                            }
                             String content = chat.getContent();
-                            
-                            if (content.startsWith("(private)")) {
-                                printMessage(String.format("[%s] %s", dt.format(TIME_FORMATTER), content));
-                            } else {
-                                printMessage(String.format("[%s] %s: %s", dt.format(TIME_FORMATTER), data.getSender(), content));
+                            stats.messageReceived();
+                            MessageHistory.Entry entry = new MessageHistory.Entry(data.getSender(), content, timestamp, chat.getTraceId());
+                            history.add(entry);
+                            if (roster != null) roster.seen(roomId, data.getSender());
+                            listeners.forEach(l -> l.onMessageReceived(entry));
+
+                            boolean mentioned = MessageHistory.mentions(content, ChatClient.this.sender);
+                            if (mentionAlert != null && (mentioned || content.startsWith("(private from"))) {
+                                mentionAlert.trigger();
                             }
+                            if (messageBell != null && MentionAlert.rings(options.getBellMode(),
+                                    mentioned || content.startsWith("(private from"))) {
+                                messageBell.trigger();
+                            }
+                            if (!chat.getReplyTo().isEmpty()) {
+                                printMessage(formatReplyContext(history.findByTraceId(chat.getReplyTo())));
+                            }
+                            printMessage(formatChatLine(entry));
                         }
                         break;
                     case FILE_ANNOUNCEMENT:
+                        if (options.isAudioOnly()) break;
                         BroadcastFileAnnouncement announce = data.getFileAnnouncement();
                         String size = String.format("%.2f KiB", (double) announce.getFileSize() / 1024.0);
-                        printMessage(String.format("%s está compartiendo '%s' (%s).", data.getSender(), announce.getFilename(), size));
-                        printMessage(String.format("   Para descargar, usa: /download %s <ruta_destino>", announce.getTransferId()));
+                        printMessage(I18n.t("share.announce", displayName(data.getSender()), ConsoleOutput.sanitize(announce.getFilename(), 255), size));
+                        printMessage(I18n.t("share.hint", announce.getTransferId()));
                         fileTransferManager.registerBroadcastTransfer(announce.getTransferId(), announce.getFileSize());
                         break;
                     case AUDIO_CHUNK:
+                        AudioChunk chunk = data.getAudioChunk();
+                        // Checked before any buffer is sized from the chunk; bad chunks are counted, not played.
+                        if (!AudioStreamer.isAcceptableChunk(chunk.getData().size(), chunk.getChannels(), options.getMaxAudioChunkBytes())) {
+                            stats.audioRejected();
+                            break;
+                        }
+                        stats.audioReceived(chunk.getData().size(), chunk.getChannels());
+                        if (silenced.contains(data.getSender())) break;
                         if (audioStreamer != null && audioStreamer.isSpeakersActive()) {
-                            audioStreamer.playAudioChunk(data.getAudioChunk().getData().toByteArray());
+                            byte[] pcm = chunk.getData().toByteArray();
+                            audioStreamer.playAudioChunk(data.getSender(), pcm, chunk.getChannels());
+                            if (options.isAudioOnly()) updateVuMeter(displayName(data.getSender()), pcm);
                         }
                         break;
                     case COMMAND:
                         com.conference.grpc.Command cmd = data.getCommand();
-                        if (cmd.getType().equals("ERROR")) {
-                            System.out.println("\r\u001b[2K Error del Servidor: " + cmd.getValue());
+                        if (cmd.getType().equals("ROOM_FULL")) {
+                            sessionResult = SessionResult.ROOM_FULL;
+                            printMessage(I18n.t("room.full"));
+                            joinLatch.countDown();
+                            finishLatch.countDown();
+                        } else if (cmd.getType().equals("ERROR")) {
+                            printMessage(I18n.t("server.error", ConsoleOutput.sanitize(cmd.getValue(), Integer.MAX_VALUE)));
+                            joinLatch.countDown();
                             finishLatch.countDown();
                         } else if (cmd.getType().equals("WELCOME")) {
                             connectionSuccessful.set(true);
-                            System.out.print("\r\u001b[2K");
-                            System.out.println("Conectado exitosamente como '" + sender + "' en sala '" + roomId + "'");
-                            System.out.println("Ya puedes chatear. Escribe /help para ver todos los comandos.");
+                            printMessage(I18n.t("join.connected", sender, roomId));
+                            printMessage(I18n.t("join.hint"));
+                            if (roster != null) showLastRoster();
+                            listeners.forEach(l -> l.onConnected(sender, roomId));
+                            beforeWelcome.forEach(listeners::forEach);
+                            beforeWelcome.clear();
+                            joinLatch.countDown();
+                        } else if (cmd.getType().equals("NUDGE_FAILED")) {
+                            printMessage(I18n.t("nudge.failed", ConsoleOutput.sanitize(cmd.getValue(), MAX_DISPLAY_NAME)));
+                        } else if (cmd.getType().equals("NUDGE")) {
+                            if (!options.isAudioOnly() && acceptNudge(lastNudge, cmd.getValue(), clock.instant().getEpochSecond())) {
+                                printMessage(ConsoleOutput.highlight(I18n.t("nudge.received", displayName(cmd.getValue()))));
+                                if (mentionAlert != null) mentionAlert.trigger();
+                                if (messageBell != null && MentionAlert.rings(options.getBellMode(), true)) messageBell.trigger();
+                            }
                         } else {
-                            printMessage(String.format("[SERVER] %s: %s", cmd.getType(), cmd.getValue()));
+                            if (cmd.getType().equals("USER_LEFT") && audioStreamer != null) {
+                                audioStreamer.forgetSender(cmd.getValue());
+                            }
+                            if (roster != null && (cmd.getType().equals("USER_JOINED") || cmd.getType().equals("USER_LEFT"))) {
+                                roster.seen(roomId, cmd.getValue());
+                            }
+                            if (cmd.getType().equals("USER_JOINED")) {
+                                history.add(new MessageHistory.Entry(data.getSender(), I18n.t("history.joined", cmd.getValue()),
+                                        clock.instant().getEpochSecond(), "", true));
+                                notifyRoster(l -> l.onUserJoined(cmd.getValue()));
+                            } else if (cmd.getType().equals("USER_LEFT")) {
+                                history.add(new MessageHistory.Entry(data.getSender(), I18n.t("history.left", cmd.getValue()),
+                                        clock.instant().getEpochSecond(), "", true));
+                                notifyRoster(l -> l.onUserLeft(cmd.getValue()));
+                            }
+                            if (!options.isAudioOnly()) printMessage(String.format("[SERVER] %s: %s", ConsoleOutput.sanitize(cmd.getType(), MAX_DISPLAY_NAME),
+                                    ConsoleOutput.sanitize(cmd.getValue(), Integer.MAX_VALUE)));
                         }
                         break;
                     default:
@@ -134,88 +424,345 @@ public class ChatClient {
                     printPrompt();
                 }
             }
-            @Override public void onError(Throwable t) { System.out.println("\r\u001b[2K Error en la conexión: " + t.getMessage()); finishLatch.countDown(); }
+            @Override public void onError(Throwable t) {
+                if (Status.fromThrowable(t).getCode() == Status.Code.UNAVAILABLE) {
+                    serverUnavailable = true;
+                }
+                if (Status.fromThrowable(t).getCode() == Status.Code.RESOURCE_EXHAUSTED && !connectionSuccessful.get()) {
+                    // Capacity rejection of the join; the message may already have come as ROOM_FULL
+                    if (sessionResult != SessionResult.ROOM_FULL) printMessage(I18n.t("room.full"));
+                    sessionResult = SessionResult.ROOM_FULL;
+                } else if (Status.fromThrowable(t).getCode() == Status.Code.RESOURCE_EXHAUSTED) {
+                    printMessage(I18n.t("connection.too_large", options.getMaxRecvSize()));
+                } else {
+                    printMessage(I18n.t("connection.error", t.getMessage()));
+                }
+                if (connectionSuccessful.get()) {
+                    listeners.forEach(l -> l.onDisconnected(roomId));
+                }
+                joinLatch.countDown();
+                finishLatch.countDown();
+            }
             @Override public void onCompleted() {
-                // If result is not already set to QUIT, it means it's a normal leave/disconnect.
-                if (sessionResult != SessionResult.QUIT_APPLICATION) {
+                // If result is not already set to QUIT, RECONNECT or ROOM_FULL, it means it's a normal leave/disconnect.
+                if (sessionResult != SessionResult.QUIT_APPLICATION && sessionResult != SessionResult.RECONNECT
+                        && sessionResult != SessionResult.ROOM_FULL) {
                     sessionResult = SessionResult.NORMAL_LEAVE;
                 }
-                System.out.println("\r\u001b[2K🔌 Desconectado de la sala.");
+                printMessage(I18n.t("connection.disconnected"));
+                if (connectionSuccessful.get()) {
+                    listeners.forEach(l -> l.onDisconnected(roomId));
+                }
+                joinLatch.countDown();
                 finishLatch.countDown();
             }
         };
+    }
 
-        requestObserver = asyncStub.joinConference(responseObserver);
-        this.audioStreamer = new AudioStreamer(requestObserver, sender, roomId);
-        this.fileTransferManager = new FileTransferManager(asyncStub, requestObserver, sender);
+    /**
+     * True when the last join failed because the server could not be reached and no join
+     * has succeeded yet; --wait-for-server only retries in that case.
+     */
+    public boolean isWaitingForServer() {
+        return serverUnavailable && !everConnected;
+    }
+
+    /** False when the machine has no microphone or speaker; audio commands are then refused. */
+    public boolean hasAudioDevices() {
+        return audioDevices;
+    }
 
+    // The channel is always plaintext; TLS is reported so the banner stays accurate if that changes.
+    private String connectionBanner() {
+        InetAddress address;
         try {
-            ConferenceData joinMessage = ConferenceData.newBuilder().setSender(sender).setRoomId(roomId)
-                    .setCommand(com.conference.grpc.Command.newBuilder().setType("JOIN").build()).build();
-            requestObserver.onNext(joinMessage);
-            Thread inputThread = new Thread(this::handleUserInput);
-            inputThread.start();
-            finishLatch.await();
-            inputThread.interrupt();
-        } catch (RuntimeException e) {
-            requestObserver.onError(e);
-            throw e;
+            address = InetAddress.getByName(host);
+        } catch (UnknownHostException e) {
+            address = null;
         }
-        return this.sessionResult;
+        return formatConnectionBanner(host, port, address, false);
+    }
+
+    static String formatConnectionBanner(String host, int port, InetAddress address, boolean tls) {
+        String peer;
+        if (address == null) {
+            peer = I18n.t("banner.unresolved");
+        } else if (address instanceof Inet6Address) {
+            peer = "[" + address.getHostAddress() + "]:" + port + " IPv6";
+        } else {
+            peer = address.getHostAddress() + ":" + port + " IPv4";
+        }
+        return I18n.t("banner.line", ClientOptions.formatServer(host, port), peer, I18n.t(tls ? "tls.on" : "tls.off"));
+    }
+
+    // Compact level line for --audio-only, redrawn in place at most every VU_METER_INTERVAL_NANOS.
+    private void updateVuMeter(String fromSender, byte[] pcm) {
+        long now = System.nanoTime();
+        if (now - lastVuMeterNanos < VU_METER_INTERVAL_NANOS) return;
+        lastVuMeterNanos = now;
+        ConsoleOutput.printProgress(formatVuMeter(fromSender, GainNormalizer.rms(pcm)));
+    }
+
+    // Maps the chunk level from -60 dBFS (empty bar) to 0 dBFS (full bar).
+    static String formatVuMeter(String fromSender, double rms) {
+        double db = rms <= 0 ? -60 : 20 * Math.log10(rms / Short.MAX_VALUE);
+        int filled = (int) Math.round(Math.max(0, Math.min(1, (db + 60) / 60)) * VU_METER_WIDTH);
+        StringBuilder bar = new StringBuilder("📻 [");
+        for (int i = 0; i < VU_METER_WIDTH; i++) bar.append(i < filled ? '#' : ' ');
+        return bar.append("] ").append(fromSender).toString();
+    }
+
+    /** A peer's name as shown on screen; the raw value is still used for filtering and logs. */
+    static String displayName(String name) {
+        String shown = ConsoleOutput.sanitize(name, MAX_DISPLAY_NAME).trim();
+        return shown.isEmpty() ? I18n.t("display.anonymous") : shown;
+    }
+
+    /**
+     * The message time to use: the sender's timestamp, or the local time when it is
+     * missing, before MIN_VALID_TIMESTAMP or more than MAX_CLOCK_SKEW_SECONDS ahead.
+     */
+    static long validTimestamp(long timestamp, long nowSeconds) {
+        if (timestamp < MIN_VALID_TIMESTAMP || timestamp > nowSeconds + MAX_CLOCK_SKEW_SECONDS) return nowSeconds;
+        return timestamp;
+    }
+
+    /**
+     * Whether a nudge from this sender is shown: at most one per NUDGE_COOLDOWN_SECONDS,
+     * later ones are dropped without restarting the wait.
+     */
+    static boolean acceptNudge(Map<String, Long> lastNudge, String from, long nowSeconds) {
+        Long last = lastNudge.get(from);
+        if (last != null && nowSeconds - last < NUDGE_COOLDOWN_SECONDS) return false;
+        lastNudge.put(from, nowSeconds);
+        return true;
+    }
+
+    static String formatWhoami(String sender, String roomId, String host, int port, boolean tls, String tracePrefix,
+                               boolean mic, boolean speakers, boolean monitoring, boolean audioAllowed) {
+        String audio = !audioAllowed ? I18n.t("whoami.audio_blocked")
+                : I18n.t(mic ? "whoami.mic_on" : "whoami.mic_off")
+                + I18n.t(speakers ? "whoami.speakers_on" : "whoami.speakers_off")
+                + (monitoring ? I18n.t("whoami.monitor") : "");
+        return I18n.t("whoami.body", sender, roomId, ClientOptions.formatServer(host, port), I18n.t(tls ? "tls.on" : "tls.off"),
+                tracePrefix, audio);
+    }
+
+    // Relays may deliver the same message more than once; remember recent trace ids and drop repeats.
+    private synchronized boolean isDuplicate(String traceId) {
+        if (traceId.isEmpty() || options.getDedupeWindow() == 0) {
+            return false;
+        }
+        boolean duplicate = !recentTraceIds.add(traceId);
+        if (duplicate) stats.duplicateDropped();
+        return duplicate;
+    }
+
+    boolean isOwnTraceId(String traceId) {
+        return traceId.startsWith(tracePrefix + "-");
+    }
+
+    // Periodic stats go to the log (stderr), never to the chat view.
+    private void startStatsLogging(int intervalSeconds) {
+        statsScheduler = Executors.newSingleThreadScheduledExecutor(r -> {
+            Thread thread = new Thread(r, "stats-logger");
+            thread.setDaemon(true);
+            return thread;
+        });
+        statsScheduler.scheduleAtFixedRate(() -> LOGGER.info("stats " + stats.formatCompact()),
+                intervalSeconds, intervalSeconds, TimeUnit.SECONDS);
     }
 
     private void handleUserInput() {
-        Scanner scanner = new Scanner(System.in);
         printPrompt();
         while (!Thread.currentThread().isInterrupted()) {
             try {
-                if (scanner.hasNextLine()) {
-                    String line = scanner.nextLine().trim();
-                    if (line.isEmpty()) {
-                        System.out.print("\r\u001b[2K"); // Clear the line before re-printing prompt
-                        printPrompt();
-                        continue;
-                    }
-                    if (line.startsWith("/")) {
-                        if (handleCommand(line)) break;
-                    } else {
-                        ChatMessage chat = ChatMessage.newBuilder().setSender(this.sender).setContent(line).setRoomId(this.roomId)
-                                .setTimestamp(Instant.now().getEpochSecond()).setTraceId(UUID.randomUUID().toString()).build();
-                        ConferenceData data = ConferenceData.newBuilder().setSender(this.sender).setRoomId(this.roomId)
-                                .setTextMessage(chat).build();
-                        requestObserver.onNext(data);
-                        printPrompt();
-                    }
-                } else { break; }
+                String rawLine = input.readLine();
+                if (rawLine == null) break;
+                String line = rawLine.trim();
+                if (line.isEmpty()) {
+                    printPrompt();
+                    continue;
+                }
+                if (line.startsWith("/")) {
+                    if (handleCommand(line)) break;
+                } else if (options.isAudioOnly()) {
+                    printMessage(I18n.t("audio_only.restricted"));
+                } else if (options.isReadOnly()) {
+                    printMessage(I18n.t("join.read_only"));
+                    printPrompt();
+                } else {
+                    String quote = pendingQuote;
+                    pendingQuote = null;
+                    sendChatMessage(quote == null ? line : quote + "\n" + line, "");
+                    printPrompt();
+                }
+            } catch (InterruptedException e) {
+                break; // Session ended; the shared reader keeps its line for the next consumer
             } catch (Exception e) { break; }
         }
     }
 
-    private boolean handleCommand(String commandLine) {
+    ChatMessage buildChatMessage(String content, String replyTo) {
+        return ChatMessage.newBuilder().setSender(this.sender).setContent(transformOutgoing(content)).setRoomId(this.roomId)
+                .setTimestamp(clock.instant().getEpochSecond()).setTraceId(tracePrefix + "-" + UUID.randomUUID())
+                .setReplyTo(replyTo).build();
+    }
+
+    private void sendChatMessage(String content, String replyTo) {
+        ChatMessage chat = buildChatMessage(content, replyTo);
+        ConferenceData data = ConferenceData.newBuilder().setSender(this.sender).setRoomId(this.roomId)
+                .setTextMessage(chat).build();
+        requestObserver.onNext(data);
+        stats.messageSent();
+        if (options.isVerbose()) {
+            // stderr keeps the trace out of the chat view and away from the prompt line
+            System.err.println("[verbose] sent trace_id=" + chat.getTraceId() + " timestamp=" + chat.getTimestamp());
+        }
+
+        // Own messages carry this session's trace prefix: they go into the session history,
+        // and any copy relayed back by the server is dropped by isOwnTraceId.
+        MessageHistory.Entry sent = new MessageHistory.Entry(sender, chat.getContent(), chat.getTimestamp(), chat.getTraceId());
+        history.add(sent);
+        listeners.forEach(l -> l.onMessageSent(sent));
+        // Without --echo-self the typed line is their only display; /scroll still shows them.
+        if (options.isEchoSelf()) printMessage(formatChatLine(sent));
+    }
+
+    // A history entry as a view line, used for live messages and when /scroll redraws the window.
+    private String formatChatLine(MessageHistory.Entry entry) {
+        String time = LocalDateTime.ofInstant(Instant.ofEpochSecond(entry.getTimestamp()), clock.getZone()).format(TIME_FORMATTER);
+        String content = entry.getContent();
+        if (entry.isSystem()) return String.format("[%s] %s", time, ConsoleOutput.sanitizeText(content));
+        boolean own = isOwnTraceId(entry.getTraceId());
+        String shown = content.trim().isEmpty() ? I18n.t("message.empty_content")
+                : !own && options.isMarkdown() && ConsoleOutput.usesAnsi() ? MarkdownRenderer.render(ConsoleOutput.sanitizeText(content))
+                : ConsoleOutput.sanitizeText(content);
+        String prefix = own ? String.format("[%s] %s: ", time, I18n.t("self.label"))
+                : content.startsWith("(private)") ? String.format("[%s] ", time)
+                : String.format("[%s] %s: ", time, displayName(entry.getSender()));
+        String line = prefix + MultilineComposer.indentContinuation(shown, prefix.length());
+        return !own && MessageHistory.mentions(content, sender) ? ConsoleOutput.highlight(line) : line;
+    }
+
+    static String formatReplyContext(MessageHistory.Entry parent) {
+        if (parent == null) {
+            return I18n.t("reply.unknown");
+        }
+        String snippet = ConsoleOutput.sanitize(parent.getContent().replace('\r', ' ').replace('\n', ' '), Integer.MAX_VALUE);
+        if (snippet.length() > 40) snippet = snippet.substring(0, 40) + "…";
+        return I18n.t("reply.context", displayName(parent.getSender()), snippet);
+    }
+
+    static final int MAX_QUOTE_LENGTH = 80;
+
+    static String formatQuote(MessageHistory.Entry entry) {
+        String text = entry.getContent().replace('\r', ' ').replace('\n', ' ');
+        if (text.length() > MAX_QUOTE_LENGTH) text = text.substring(0, MAX_QUOTE_LENGTH) + "…";
+        return "> " + displayName(entry.getSender()) + ": " + text;
+    }
+
+    private void quote(String reference) {
+        List<MessageHistory.Entry> matches = history.resolve(reference);
+        if (matches.isEmpty()) {
+            printMessage(I18n.t("message.not_found", reference));
+        } else if (matches.size() > 1) {
+            printMessage(I18n.t("message.ambiguous", reference));
+        } else {
+            pendingQuote = formatQuote(matches.get(0));
+            printMessage(pendingQuote);
+            printMessage(I18n.t("quote.hint"));
+        }
+    }
+
+    private void replyTo(String reference, String text) {
+        List<MessageHistory.Entry> matches = history.resolve(reference);
+        if (matches.isEmpty()) {
+            printMessage(I18n.t("message.not_found", reference));
+        } else if (matches.size() > 1) {
+            printMessage(I18n.t("message.ambiguous", reference));
+        } else {
+            MessageHistory.Entry parent = matches.get(0);
+            printMessage(formatReplyContext(parent));
+            sendChatMessage(text, parent.getTraceId());
+        }
+    }
+
+    // Runs on the input thread, so it can take the following lines directly from the console.
+    private void composeMultiline() {
+        printMessage(I18n.t("multiline.start"));
+        MultilineComposer composer = new MultilineComposer();
+        try {
+            do {
+                ConsoleOutput.printPrompt("… ");
+                String line = input.readLine();
+                if (line == null) return;
+                if (composer.accept(line)) break;
+            } while (true);
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return;
+        }
+        if (composer.isEmpty()) {
+            printMessage(I18n.t("message.empty"));
+        } else {
+            sendChatMessage(composer.text(), "");
+        }
+    }
+
+    private boolean handleCommand(String typedLine) {
+        String commandLine = options.getAliases().expand(typedLine);
+        listeners.forEach(l -> l.onCommand(commandLine));
         String[] parts = commandLine.split(" ", 3);
         String command = parts[0].toLowerCase();
         boolean shouldBreakLoop = false;
+        if (options.isReadOnly() && SENDING_COMMANDS.contains(command)) {
+            printMessage(I18n.t("command.read_only", command));
+            printPrompt();
+            return false;
+        }
+        // Checked on the expanded line, so an alias such as /q behaves like the command it names.
+        if (options.isAudioOnly() && !AUDIO_ONLY_COMMANDS.contains(command)) {
+            printMessage(I18n.t("audio_only.restricted"));
+            return false;
+        }
 
         switch (command) {
-            case "/help": printHelp(); printPrompt(); break;
+            case "/help": printHelp(options.getAliases()); printPrompt(); break;
             case "/quit": case "/exit":
-                printMessage("Cerrando aplicación...");
+                printMessage(I18n.t("command.quit"));
                 this.sessionResult = SessionResult.QUIT_APPLICATION;
                 requestObserver.onCompleted();
                 shouldBreakLoop = true;
                 break;
             case "/leave":
-                 printMessage("Saliendo de la sala...");
+                 printMessage(I18n.t("command.leave"));
                  this.sessionResult = SessionResult.NORMAL_LEAVE;
                  requestObserver.onCompleted();
                  shouldBreakLoop = true;
                  break;
+            case "/reconnect":
+                stats.reconnected();
+                this.sessionResult = SessionResult.RECONNECT;
+                restoreAudioOnJoin = audioStreamer.isAudioActive();
+                // One notice for the whole teardown instead of "off" followed by "reconnecting"
+                printMessage(I18n.t(restoreAudioOnJoin ? "connection.reconnecting_audio" : "connection.reconnecting", roomId));
+                audioStreamer.shutdown(false);
+                reportMicChange(restoreAudioOnJoin);
+                requestObserver.onCompleted();
+                channel.enterIdle(); // Drop the transport so the next join opens a fresh connection
+                shouldBreakLoop = true;
+                break;
+            case "/multiline":
+                composeMultiline();
+                printPrompt();
+                break;
             case "/msg":
                 if (parts.length >= 3) {
-                    PrivateMessage pvtMsg = PrivateMessage.newBuilder().setRecipientId(parts[1]).setContent(parts[2]).build();
+                    PrivateMessage pvtMsg = PrivateMessage.newBuilder().setRecipientId(parts[1]).setContent(transformOutgoing(parts[2])).build();
                     ConferenceData data = ConferenceData.newBuilder().setSender(sender).setRoomId(roomId).setPrivateMessage(pvtMsg).build();
                     requestObserver.onNext(data);
-                } else { printMessage("Uso: /msg <usuario> <mensaje>"); }
+                } else { printMessage(I18n.t("usage", "/msg <usuario> <mensaje>")); }
                 printPrompt();
                 break;
             default:
@@ -228,38 +775,461 @@ public class ChatClient {
     private void handleOtherCommands(String command, String[] parts) {
         switch(command) {
             case "/mic":
-                if (parts.length > 1 && parts[1].equalsIgnoreCase("on")) audioStreamer.startAudio();
-                else if (parts.length > 1 && parts[1].equalsIgnoreCase("off")) audioStreamer.stopAudio();
-                else printMessage("Uso: /mic <on|off>");
+                String micUnavailable = parts.length > 1 && parts[1].equalsIgnoreCase("on") ? audioUnavailable() : null;
+                if (micUnavailable != null) {
+                    printMessage(micUnavailable);
+                } else if (parts.length > 1 && parts[1].equalsIgnoreCase("on")) {
+                    // First use: calibrate before transmitting (not while /monitor holds the mic).
+                    // A failed attempt is not repeated; /mic calibrate retries on demand.
+                    if (micGain == null && !calibrationTried && !audioStreamer.isAudioActive() && !audioStreamer.isMonitoring()) calibrateMic();
+                    setMic(true);
+                } else if (parts.length > 1 && parts[1].equalsIgnoreCase("calibrate")) {
+                    if (!audioDevices) printMessage(I18n.t("audio.no_devices"));
+                    else if (audioStreamer.isAudioActive() || audioStreamer.isMonitoring()) printMessage(I18n.t("calibration.mic_busy"));
+                    else calibrateMic();
+                } else if (parts.length > 1 && parts[1].equalsIgnoreCase("off")) {
+                    setMic(false);
+                } else {
+                    printMessage(I18n.t("usage", "/mic <on|off|calibrate>"));
+                }
+                printPrompt();
+                break;
+            case "/monitor":
+                if (parts.length > 1 && parts[1].equalsIgnoreCase("off")) {
+                    audioStreamer.stopMonitor();
+                } else if (parts.length > 1 && parts[1].equalsIgnoreCase("on") && !audioDevices) {
+                    printMessage(I18n.t("audio.no_devices"));
+                } else if (parts.length > 1 && parts[1].equalsIgnoreCase("on")) {
+                    try {
+                        double gain = parts.length == 3 ? Double.parseDouble(parts[2]) : 1.0;
+                        if (gain < 0 || gain > MAX_MONITOR_GAIN) throw new NumberFormatException();
+                        audioStreamer.startMonitor(gain);
+                    } catch (NumberFormatException e) {
+                        printMessage(I18n.t("audio.monitor_gain_range", MAX_MONITOR_GAIN));
+                    }
+                } else {
+                    printMessage(I18n.t("usage", "/monitor <on|off> [ganancia]"));
+                }
+                printPrompt();
+                break;
+            case "/test-audio":
+                printMessage(audioStreamer.describeDevices());
+                printPrompt();
+                break;
+            case "/find-device":
+                if (!audioDevices) printMessage(I18n.t("audio.no_devices"));
+                else if (parts.length >= 2) findDevice(parts.length == 3 ? parts[1] + " " + parts[2] : parts[1]);
+                else printMessage(I18n.t("usage", "/find-device <texto>"));
+                printPrompt();
+                break;
+            case "/tone":
+                String toneUnavailable = audioUnavailable();
+                if (toneUnavailable != null) {
+                    printMessage(toneUnavailable);
+                } else if (parts.length == 3) {
+                    try {
+                        audioStreamer.sendTone(Integer.parseInt(parts[1]), Double.parseDouble(parts[2]));
+                    } catch (NumberFormatException e) {
+                        printMessage(I18n.t("usage", "/tone <hz> <segundos>"));
+                    }
+                } else { printMessage(I18n.t("usage", "/tone <hz> <segundos>")); }
+                printPrompt();
+                break;
+            case "/quote":
+                if (parts.length == 2 && parts[1].equalsIgnoreCase("cancel")) {
+                    pendingQuote = null;
+                    printMessage(I18n.t("quote.cancelled"));
+                } else if (parts.length == 2) {
+                    quote(parts[1]);
+                } else {
+                    printMessage(I18n.t("usage", "/quote <n|trace_id>"));
+                }
+                printPrompt();
+                break;
+            case "/reply":
+                if (parts.length == 3) replyTo(parts[1], parts[2]);
+                else printMessage(I18n.t("usage", "/reply <n|trace_id> <mensaje>"));
+                printPrompt();
+                break;
+            case "/stats":
+                if (parts.length > 1 && parts[1].equalsIgnoreCase("reset")) {
+                    stats.reset();
+                    printMessage(I18n.t("stats.reset"));
+                } else {
+                    printMessage(stats.format());
+                }
                 printPrompt();
                 break;
+            case "/save":
+                if (parts.length >= 2) saveBookmark(parts[1], parts.length == 3 ? parts[2] : "");
+                else printMessage(I18n.t("usage", "/save <n|trace_id> [nota]"));
+                printPrompt();
+                break;
+            case "/export":
+                if (parts.length >= 2) exportTranscript(parts.length == 3 ? parts[1] + " " + parts[2] : parts[1]);
+                else printMessage(I18n.t("usage", "/export <archivo.md|archivo.html>"));
+                printPrompt();
+                break;
+            case "/bookmarks":
+                showBookmarks();
+                printPrompt();
+                break;
+            case "/mentions":
+                showMentions();
+                printPrompt();
+                break;
+            case "/roominfo":
+                requestRoomInfo();
+                break;
+            case "/invite":
+                if (parts.length == 2) {
+                    String link = new RoomInvite(host, port, roomId, parts[1]).toUri();
+                    printMessage(I18n.t("invite.link", parts[1], link));
+                    printMessage(I18n.t("invite.open", link));
+                } else {
+                    printMessage(I18n.t("usage", "/invite <usuario>"));
+                }
+                printPrompt();
+                break;
+            case "/silence":
+                if (parts.length == 2) {
+                    silenced.add(parts[1]);
+                    if (audioStreamer != null) audioStreamer.forgetSender(parts[1]);
+                    printMessage(I18n.t("silence.on", parts[1]));
+                } else {
+                    printMessage(I18n.t("usage", "/silence <usuario>"));
+                }
+                printPrompt();
+                break;
+            case "/unsilence":
+                if (parts.length == 2 && silenced.remove(parts[1])) printMessage(I18n.t("silence.off", parts[1]));
+                else if (parts.length == 2) printMessage(I18n.t("silence.not_silenced", parts[1]));
+                else printMessage(I18n.t("usage", "/unsilence <usuario>"));
+                printPrompt();
+                break;
+            case "/silenced":
+                printMessage(silenced.isEmpty() ? I18n.t("silence.none")
+                        : I18n.t("silence.list", String.join(", ", new TreeSet<>(silenced))));
+                printPrompt();
+                break;
+            case "/scroll":
+                if (parts.length == 2 && parts[1].matches("(?i)up|down|top|bottom")) scroll(parts[1]);
+                else printMessage(I18n.t("usage", "/scroll <up|down|top|bottom>"));
+                printPrompt();
+                break;
+            case "/nudge":
+                if (parts.length != 2) {
+                    printMessage(I18n.t("usage", "/nudge <usuario>"));
+                } else if (parts[1].equals(sender)) {
+                    printMessage(I18n.t("nudge.self"));
+                } else {
+                    PrivateMessage nudge = PrivateMessage.newBuilder().setRecipientId(parts[1]).setKind("NUDGE").build();
+                    requestObserver.onNext(ConferenceData.newBuilder().setSender(sender).setRoomId(roomId).setPrivateMessage(nudge).build());
+                    printMessage(I18n.t("nudge.sent", parts[1]));
+                }
+                printPrompt();
+                break;
+            case "/whoami":
+                printMessage(formatWhoami(sender, roomId, host, port, false, tracePrefix,
+                        audioStreamer.isAudioActive(), audioStreamer.isSpeakersActive(),
+                        audioStreamer.isMonitoring(), audioAllowed));
+                printPrompt();
+                break;
+            case "/echo":
+                if (parts.length >= 2) {
+                    echo(parts.length == 3 ? parts[1] + " " + parts[2] : parts[1]);
+                } else {
+                    printMessage(I18n.t("usage", "/echo <texto>"));
+                    printPrompt();
+                }
+                break;
             case "/upload":
                 if (parts.length == 3) fileTransferManager.uploadFile(parts[1], parts[2], roomId);
-                else printMessage("Uso: /upload <usuario> <ruta_archivo>");
+                else printMessage(I18n.t("usage", "/upload <usuario> <ruta_archivo>"));
                 break;
             case "/upload-all":
                 if (parts.length == 2) fileTransferManager.broadcastFile(parts[1], roomId);
-                else printMessage("Uso: /upload-all <ruta_archivo>");
+                else printMessage(I18n.t("usage", "/upload-all <ruta_archivo>"));
                 break;
             case "/download":
                 if (parts.length == 3) fileTransferManager.downloadBroadcastFile(parts[1], parts[2]);
-                else printMessage("Uso: /download <id_transferencia> <ruta_destino>");
+                else printMessage(I18n.t("usage", "/download <id_transferencia> <ruta_destino>"));
                 break;
             case "/accept":
                  if (parts.length == 3) fileTransferManager.acceptFile(parts[1], parts[2], roomId);
-                 else printMessage("Uso: /accept <transferId> <ruta_destino>");
+                 else printMessage(I18n.t("usage", "/accept <transferId> <ruta_destino>"));
                 break;
             case "/reject":
                 if (parts.length == 2) fileTransferManager.rejectFile(parts[1], roomId);
-                else printMessage("Uso: /reject <transferId>");
+                else printMessage(I18n.t("usage", "/reject <transferId>"));
                 break;
             default:
-                printMessage("Comando no reconocido: " + command);
+                printMessage(I18n.t("command.unknown", command));
                 printPrompt();
                 break;
         }
     }
     
+    private static RosterCache loadRoster(Clock clock) {
+        RosterCache cache = new RosterCache(clock);
+        try {
+            cache.load();
+        } catch (IOException e) {
+            System.err.println(I18n.t("roster.read_error", RosterCache.DEFAULT_FILE, e.getMessage()));
+        }
+        return cache;
+    }
+
+    private void saveRoster() {
+        if (roster == null) return;
+        try {
+            roster.save();
+        } catch (IOException e) {
+            printMessage(I18n.t("roster.save_error", RosterCache.DEFAULT_FILE, e.getMessage()));
+        }
+    }
+
+    private void showLastRoster() {
+        Map<String, Long> last = roster.lastSeen(roomId);
+        last.remove(sender);
+        if (last.isEmpty()) return;
+        DateTimeFormatter format = DateTimeFormatter.ofPattern("dd-MM HH:mm");
+        StringJoiner names = new StringJoiner(", ");
+        for (Map.Entry<String, Long> member : last.entrySet()) {
+            LocalDateTime seen = LocalDateTime.ofInstant(Instant.ofEpochSecond(member.getValue()), clock.getZone());
+            names.add(displayName(member.getKey()) + " (" + seen.format(format) + ")");
+        }
+        printMessage(I18n.t("roster.last", names));
+    }
+
+    private void setMic(boolean on) {
+        boolean before = audioStreamer.isAudioActive();
+        if (on) audioStreamer.startAudio();
+        else audioStreamer.stopAudio();
+        reportMicChange(before);
+    }
+
+    // Tells listeners whether the microphone now transmits, if that changed since `before`.
+    private void reportMicChange(boolean before) {
+        boolean after = audioStreamer.isAudioActive();
+        if (before != after) {
+            listeners.forEach(l -> l.onAudioChanged(after));
+        }
+    }
+
+    // Moves the history window and redraws the screen with it; without cursor control the window is just appended.
+    private void scroll(String direction) {
+        if (direction.equalsIgnoreCase("up")) history.scrollUp();
+        else if (direction.equalsIgnoreCase("down")) history.scrollDown();
+        else if (direction.equalsIgnoreCase("top")) history.scrollTop();
+        else history.scrollBottom();
+        List<MessageHistory.Entry> window = history.window();
+        if (window.isEmpty()) {
+            printMessage(I18n.t("scroll.empty"));
+            return;
+        }
+        int last = history.size() - history.getHidden();
+        ConsoleOutput.clearScreen();
+        printMessage(I18n.t("scroll.header", last - window.size() + 1, last, history.size()));
+        window.forEach(entry -> printMessage(formatChatLine(entry)));
+        if (history.getHidden() > 0) printMessage(I18n.t("scroll.more", history.getHidden()));
+    }
+
+    // Selects the input and/or output device whose name matches; ambiguous matches are listed.
+    private void findDevice(String query) {
+        List<String> mics = AudioDevices.findMatches(audioStreamer.inputDeviceNames(), query);
+        List<String> outputs = AudioDevices.findMatches(audioStreamer.outputDeviceNames(), query);
+        if (mics.isEmpty() && outputs.isEmpty()) {
+            printMessage(I18n.t("device.none", query));
+            return;
+        }
+        if (mics.size() == 1) {
+            micDevice = mics.get(0);
+            audioStreamer.setMicDevice(micDevice);
+            printMessage(I18n.t("device.mic", micDevice));
+        } else if (mics.size() > 1) {
+            printMessage(I18n.t("device.mic_candidates", query, String.join(", ", mics)));
+        }
+        if (outputs.size() == 1) {
+            speakerDevice = outputs.get(0);
+            audioStreamer.setSpeakerDevice(speakerDevice);
+            printMessage(I18n.t("device.speaker", speakerDevice));
+        } else if (outputs.size() > 1) {
+            printMessage(I18n.t("device.speaker_candidates", query, String.join(", ", outputs)));
+        }
+        if ((mics.size() == 1 || outputs.size() == 1) && (audioStreamer.isAudioActive() || audioStreamer.isSpeakersActive())) {
+            printMessage(I18n.t("device.next_time"));
+        }
+    }
+
+    private void calibrateMic() {
+        calibrationTried = true;
+        try {
+            printMessage(I18n.t("calibration.silence"));
+            double noise = MicCalibration.noiseLevel(audioStreamer.measureMicLevels(MicCalibration.NOISE_SECONDS));
+            printMessage(I18n.t("calibration.speak"));
+            double speech = MicCalibration.speechLevel(audioStreamer.measureMicLevels(MicCalibration.SPEECH_SECONDS));
+            Double gain = MicCalibration.computeGain(noise, speech);
+            if (gain == null) {
+                printMessage(I18n.t("calibration.no_speech"));
+                return;
+            }
+            micGain = gain;
+            silenceThreshold = MicCalibration.computeThreshold(noise, speech);
+            audioStreamer.setMicGain(gain);
+            audioStreamer.setSilenceThreshold(silenceThreshold);
+            micCalibration.save(gain, silenceThreshold, noise, speech);
+            printMessage(I18n.t("calibration.done", gain, silenceThreshold, MicCalibration.DEFAULT_FILE));
+        } catch (LineUnavailableException e) {
+            printMessage(I18n.t("audio.device_error", e.getMessage()));
+        } catch (IOException e) {
+            printMessage(I18n.t("calibration.save_error", MicCalibration.DEFAULT_FILE, e.getMessage()));
+        }
+    }
+
+    private void saveBookmark(String reference, String note) {
+        List<MessageHistory.Entry> matches = history.resolve(reference);
+        if (matches.isEmpty()) {
+            printMessage(I18n.t("message.not_found", reference));
+            return;
+        }
+        if (matches.size() > 1) {
+            printMessage(I18n.t("message.ambiguous_list", reference));
+            for (MessageHistory.Entry entry : matches) {
+                printMessage(String.format("  %s  %s: %s", entry.getTraceId(), displayName(entry.getSender()), ConsoleOutput.sanitizeText(entry.getContent())));
+            }
+            return;
+        }
+        try {
+            bookmarks.save(matches.get(0), roomId, note);
+            printMessage(I18n.t("bookmark.saved", Bookmarks.DEFAULT_FILE));
+        } catch (IOException e) {
+            printMessage(I18n.t("bookmark.save_error", e.getMessage()));
+        }
+    }
+
+    private void exportTranscript(String file) {
+        List<MessageHistory.Entry> entries = history.snapshot();
+        try {
+            TranscriptExport.write(Paths.get(file), roomId, entries, clock.getZone());
+            printMessage(I18n.t("export.done", entries.size(), file));
+        } catch (IllegalArgumentException | IOException e) {
+            printMessage(I18n.t("export.error", e.getMessage()));
+        }
+    }
+
+    private void showBookmarks() {
+        try {
+            List<Map<String, String>> saved = bookmarks.list();
+            if (saved.isEmpty()) {
+                printMessage(I18n.t("bookmark.none"));
+                return;
+            }
+            printMessage(I18n.t("bookmark.title"));
+            for (Map<String, String> bookmark : saved) {
+                String note = bookmark.getOrDefault("note", "");
+                printMessage(String.format("  [%s] %s: %s%s", displayName(bookmark.get("room_id")), displayName(bookmark.get("sender")),
+                        ConsoleOutput.sanitizeText(String.valueOf(bookmark.get("content"))),
+                        note.isEmpty() ? "" : "  (" + ConsoleOutput.sanitizeText(note) + ")"));
+            }
+        } catch (IOException e) {
+            printMessage(I18n.t("bookmark.read_error", e.getMessage()));
+        }
+    }
+
+    private void showMentions() {
+        List<MessageHistory.Entry> mentions = history.mentionsOf(sender);
+        if (mentions.isEmpty()) {
+            printMessage(I18n.t("mentions.none", sender));
+            return;
+        }
+        printMessage(I18n.t("mentions.title", sender));
+        for (MessageHistory.Entry entry : mentions) {
+            LocalDateTime dt = LocalDateTime.ofInstant(Instant.ofEpochSecond(entry.getTimestamp()), clock.getZone());
+            printMessage(String.format("[%s] %s: %s", dt.format(TIME_FORMATTER), displayName(entry.getSender()), ConsoleOutput.sanitizeText(entry.getContent())));
+        }
+    }
+
+    // Why audio cannot be used now (no devices, the room forbids it, or its policy has not
+    // arrived yet), or null if it can. Waits briefly for the policy right after joining.
+    private String audioUnavailable() {
+        if (!audioDevices) return I18n.t("audio.no_devices");
+        try {
+            if (!audioPolicyLatch.await(AUDIO_POLICY_WAIT_SECONDS, TimeUnit.SECONDS)) return I18n.t("room.audio_policy_pending");
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return I18n.t("room.audio_policy_pending");
+        }
+        return audioAllowed ? null : I18n.t("room.no_audio");
+    }
+
+    // Caches whether the room allows audio; until the answer arrives audio counts as not allowed.
+    // Servers without GetRoomInfo, or that fail to answer, are assumed to allow it.
+    private void fetchAudioPolicy() {
+        audioAllowed = false;
+        CountDownLatch latch = new CountDownLatch(1);
+        audioPolicyLatch = latch;
+        asyncStub.getRoomInfo(RoomInfoRequest.newBuilder().setRoomId(roomId).build(), new StreamObserver<RoomInfo>() {
+            @Override public void onNext(RoomInfo info) { audioAllowed = info.getAudioAllowed(); latch.countDown(); }
+            @Override public void onError(Throwable t) { audioAllowed = true; latch.countDown(); }
+            @Override public void onCompleted() { latch.countDown(); }
+        });
+    }
+
+    private void requestRoomInfo() {
+        RoomInfoRequest request = RoomInfoRequest.newBuilder().setRoomId(roomId).build();
+        asyncStub.getRoomInfo(request, new StreamObserver<RoomInfo>() {
+            @Override
+            public void onNext(RoomInfo info) {
+                printMessage(formatRoomInfo(info, clock.getZone()));
+            }
+            @Override
+            public void onError(Throwable t) {
+                Status.Code code = Status.fromThrowable(t).getCode();
+                if (code == Status.Code.NOT_FOUND) printMessage(I18n.t("roominfo.not_found", roomId));
+                else if (code == Status.Code.UNIMPLEMENTED) printMessage(I18n.t("roominfo.unsupported"));
+                else printMessage(I18n.t("roominfo.error", t.getMessage()));
+                printPrompt();
+            }
+            @Override
+            public void onCompleted() { printPrompt(); }
+        });
+    }
+
+    private void echo(String payload) {
+        long start = System.nanoTime();
+        asyncStub.echo(EchoRequest.newBuilder().setPayload(payload).build(), new StreamObserver<EchoResponse>() {
+            @Override
+            public void onNext(EchoResponse response) {
+                long millis = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - start);
+                stats.latency(millis);
+                LocalDateTime serverTime = LocalDateTime.ofInstant(Instant.ofEpochMilli(response.getServerTime()), clock.getZone());
+                printMessage(I18n.t("echo.result", response.getPayload(), millis,
+                        serverTime.format(DateTimeFormatter.ofPattern("HH:mm:ss.SSS"))));
+            }
+            @Override
+            public void onError(Throwable t) {
+                if (Status.fromThrowable(t).getCode() == Status.Code.UNIMPLEMENTED) printMessage(I18n.t("echo.unsupported"));
+                else printMessage(I18n.t("echo.error", t.getMessage()));
+                printPrompt();
+            }
+            @Override
+            public void onCompleted() { printPrompt(); }
+        });
+    }
+
+    static String formatRoomInfo(RoomInfo info, ZoneId zone) {
+        LocalDateTime created = LocalDateTime.ofInstant(Instant.ofEpochSecond(info.getCreatedAt()), zone);
+        return String.join("\n",
+                I18n.t("roominfo.title"),
+                String.format("  %-12s %s", I18n.t("roominfo.name"), info.getRoomId()),
+                String.format("  %-12s %d", I18n.t("roominfo.members"), info.getMemberCount()),
+                String.format("  %-12s %s", I18n.t("roominfo.created"), created.format(DateTimeFormatter.ofPattern("yyyy-MM-dd HH:mm"))),
+                String.format("  %-12s %s", I18n.t("roominfo.password"), I18n.t(info.getRequiresPassword() ? "roominfo.yes" : "roominfo.no")),
+                String.format("  %-12s %s", I18n.t("roominfo.audio"),
+                        I18n.t(info.getAudioAllowed() ? "roominfo.audio_allowed" : "roominfo.audio_blocked")));
+    }
+
     private void handleP2PFileRequestNotification(String message) {
         String[] parts = message.split(":");
         if (parts.length >= 6) {
@@ -267,34 +1237,75 @@ public class ChatClient {
             try {
                 long fileSize = Long.parseLong(parts[4]);
                 fileTransferManager.registerPendingP2PTransfer(transferId, fileSender, fileSize);
-                printMessage("\nSolicitud de archivo 1-a-1 recibida:");
-                printMessage("  De: " + fileSender);
-                printMessage("  Archivo: " + filename + " (" + fileSize + " bytes)");
-                printMessage("  Para aceptar: /accept " + transferId + " <ruta_destino>");
-                printMessage("  Para rechazar: /reject " + transferId);
+                printMessage(I18n.t("p2p.request"));
+                printMessage(I18n.t("p2p.request_from", displayName(fileSender)));
+                printMessage(I18n.t("p2p.request_file", filename, fileSize));
+                printMessage(I18n.t("p2p.request_accept", transferId));
+                printMessage(I18n.t("p2p.request_reject", transferId));
             } catch (NumberFormatException e) {
-                printMessage("Error: Formato de tamaño de archivo inválido en la notificación.");
+                printMessage(I18n.t("p2p.invalid_size"));
             }
         }
     }
     
+    // Prints a label and waits for the next line; null means stdin is exhausted.
+    private static String prompt(ConsoleInput input, String label) {
+        ConsoleOutput.flush();
+        System.out.print(label);
+        System.out.flush();
+        try {
+            String line = input.readLine();
+            return line == null ? null : line.trim();
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return null;
+        }
+    }
+
     private static void printWelcome() {
         System.out.println("\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         System.out.println("           CHAT gRPC - Cliente Java");
         System.out.println("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
     }
 
-    private static void printHelp() {
+    private static void printHelp(CommandAliases aliases) {
         System.out.println("\n═══════════════════════════════════════════════════════");
         System.out.println("                   COMANDOS DISPONIBLES");
         System.out.println("═══════════════════════════════════════════════════════");
         System.out.println("\n\uD83D\uDCDD Comandos de Chat y Sala:");
         System.out.println("  /help                          - Mostrar esta ayuda");
         System.out.println("  /msg <usuario> <mensaje>       - Enviar un mensaje privado");
+        System.out.println("  /nudge <usuario>               - Enviar un toque para llamar la atención de un usuario");
+        System.out.println("  /multiline                     - Escribir un mensaje de varias líneas (termina con \".\")");
+        System.out.println("  /reply <n|trace_id> <mensaje>  - Responder al n-ésimo mensaje más reciente (1 = último)");
+        System.out.println("  /quote <n|trace_id>            - Citar un mensaje encima del próximo que escribas");
+        System.out.println("  /save <n|trace_id> [nota]      - Guardar un mensaje en bookmarks.json");
+        System.out.println("  /bookmarks                     - Listar los mensajes guardados");
+        System.out.println("  /export <archivo.md|.html>     - Exportar los mensajes de la sesión a Markdown o HTML");
+        System.out.println("  /scroll <up|down|top|bottom>   - Recorrer los mensajes recientes de la sala (ver --scrollback)");
+        System.out.println("  /mentions                      - Ver los mensajes que te mencionan (@tu_nombre)");
+        System.out.println("  /stats [reset]                 - Ver o reiniciar las estadísticas de la sesión");
+        System.out.println("  /roominfo                      - Ver información de la sala actual");
+        System.out.println("  /invite <usuario>              - Generar un enlace elo330:// a esta sala");
+        System.out.println("  /echo <texto>                  - Medir la ida y vuelta al servidor");
+        System.out.println("  /whoami                        - Ver tu nombre, sala, servidor y estado del audio");
         System.out.println("  /leave                         - Salir de la sala actual para unirse a otra");
+        System.out.println("  /reconnect                     - Reabrir la conexión con el servidor en la misma sala");
         System.out.println("  /quit, /exit                   - Cerrar la aplicación");
         System.out.println("\n\uD83C\uDFA4 Comandos de Audio:");
         System.out.println("  /mic <on|off>                  - Activar o desactivar micrófono y altavoces");
+        System.out.println("  /mic calibrate                 - Volver a calibrar la ganancia del micrófono");
+        System.out.println("  /tone <hz> <segundos>          - Enviar un tono de prueba a la sala");
+        System.out.println("  /test-audio                    - Ver los formatos que soportan tu micrófono y altavoces");
+        System.out.println("  /find-device <texto>           - Elegir micrófono y/o altavoces por parte de su nombre");
+        System.out.println("  /monitor <on|off> [ganancia]   - Escuchar tu propio micrófono localmente (usa audífonos)");
+        System.out.println("  /silence <usuario>             - Dejar de escuchar el audio de un usuario (su chat se sigue viendo)");
+        System.out.println("  /unsilence <usuario>           - Volver a escuchar el audio de un usuario");
+        System.out.println("  /silenced                      - Listar los usuarios con el audio silenciado");
+        System.out.println("\n\u2328\uFE0F  Atajos:");
+        for (Map.Entry<String, String> alias : aliases.all().entrySet()) {
+            System.out.println(String.format("  %-30s - %s", alias.getKey(), alias.getValue()));
+        }
         System.out.println("\n\uD83D\uDCE4 Comandos de Archivos (1 a 1):");
         System.out.println("  /upload <usuario> <archivo>    - Enviar un archivo a un usuario");
         System.out.println("  /accept <id> <ruta>            - Aceptar transferencia");
@@ -306,54 +1317,138 @@ public class ChatClient {
     }
 
     public static void main(String[] args) {
+        // Option errors are reported in the LANG language; --lang takes over once parsed.
+        I18n.setLang(I18n.Lang.fromEnvironment(System.getenv("LANG")));
+        ClientOptions options;
+        try {
+            options = ClientOptions.parse(args);
+        } catch (IllegalArgumentException e) {
+            System.err.println(I18n.t("app.error", e.getMessage()));
+            ClientOptions.printUsage();
+            System.exit(1);
+            return;
+        }
+        // Before anything is printed: the host and port prompts, check and replay are translated too.
+        I18n.setLang(options.getLang() != null ? options.getLang() : I18n.Lang.fromEnvironment(System.getenv("LANG")));
+        if (options.isCheck()) {
+            System.exit(ConnectivityCheck.run(options.getHost(), options.getPort(), options.getJoinTimeoutSeconds()));
+        }
+        if (options.getReplayPath() != null) {
+            System.exit(SessionReplay.run(Paths.get(options.getReplayPath()), options.getReplaySpeed(), Clock.systemDefaultZone()));
+        }
         printWelcome();
-        Scanner scanner = new Scanner(System.in);
-        System.out.print("Dirección del servidor [localhost]: ");
-        String host = scanner.nextLine().trim();
-        if (host.isEmpty()) host = "localhost";
-        System.out.print("Puerto del servidor [50051]: ");
-        String portStr = scanner.nextLine().trim();
-        int port = portStr.isEmpty() ? 50051 : Integer.parseInt(portStr);
-        ChatClient client = new ChatClient(host, port);
+        ConsoleInput input = new ConsoleInput(System.in);
+        String host = options.getHost();
+        int port = options.getPort();
+        if (!options.hasServer()) {
+            host = prompt(input, I18n.t("prompt.server_host"));
+            if (host == null) return;
+            if (host.isEmpty()) host = ClientOptions.DEFAULT_HOST;
+            String portStr = prompt(input, I18n.t("prompt.server_port"));
+            if (portStr == null) return;
+            port = portStr.isEmpty() ? ClientOptions.DEFAULT_PORT : Integer.parseInt(portStr);
+        }
+        if (options.isPlain()) ConsoleOutput.setPlain(true);
+        if (options.isNoRedraw()) ConsoleOutput.setNoRedraw(true);
+        ConsoleOutput.setCoalesceMillis(options.getRenderCoalesceMillis());
+        ChatClient client = new ChatClient(host, port, options, input);
+        if (!client.hasAudioDevices()) System.out.println(I18n.t("audio.no_devices_notice"));
+        // Output piped into a process that exited: stop audio, leave the room and exit quietly.
+        ConsoleOutput.onClosed(() -> {
+            client.shutdown();
+            System.exit(0);
+        });
+        if (options.getControlPort() > 0) {
+            try {
+                ControlServer control = new ControlServer(options.getControlPort(), input, client, Clock.systemDefaultZone());
+                control.start();
+                System.out.println(I18n.t("control.token", control.getPort(), control.getToken()));
+            } catch (IOException e) {
+                System.err.println(I18n.t("control.open_error", options.getControlPort(), e.getMessage()));
+            }
+        }
+        SessionRecorder recorder = null;
+        if (options.getRecordSessionPath() != null) {
+            try {
+                recorder = new SessionRecorder(Paths.get(options.getRecordSessionPath()), Clock.systemDefaultZone());
+                client.addEventListener(recorder);
+            } catch (IOException e) {
+                System.err.println(I18n.t("record.open_error", options.getRecordSessionPath(), e.getMessage()));
+            }
+        }
         System.out.println("\n──────────────────────────────────────────────────");
-        System.out.println("                UNIRSE A UNA SALA");
+        System.out.println(I18n.t("join.title"));
         System.out.println("──────────────────────────────────────────────────");
 
+        // Command-line name/room only apply to the first join; later rooms are always prompted.
+        String initialName = options.getName();
+        String initialRoom = options.getRoom();
+        int exitCode = 0;
         while (true) {
             
-            System.out.print("\n🏠 ID de la sala (o escribe 'quit' para salir): ");
-            String roomId = scanner.nextLine().trim();
-            if (roomId.equalsIgnoreCase("quit")) break;
+            String roomId = initialRoom != null ? initialRoom : prompt(input, I18n.t("prompt.room"));
+            if (roomId == null || roomId.equalsIgnoreCase("quit")) break;
 
             if (roomId.isEmpty()) {
-                System.err.println("❌ ¡El ID de la sala no puede estar vacíos!");
+                System.err.println(I18n.t("join.empty_room"));
                 continue;
             }
 
-            System.out.print("👤 Tu nombre de usuario: ");
-            String sender = scanner.nextLine().trim();
-
-            
+            String sender = initialName != null ? initialName : prompt(input, I18n.t("prompt.name"));
+            if (sender == null) break;
 
             if(sender.isEmpty()){
-                System.err.println("❌ ¡El nombre de usuario no puede estar vacíos!");
+                System.err.println(I18n.t("join.empty_name"));
                 continue;
             }
             
+            initialName = null;
+            initialRoom = null;
             try {
                 SessionResult result = client.startChat(sender, roomId);
+                long backoffSeconds = 1;
+                long waitStart = System.nanoTime();
+                while ((options.isWaitForServer() && result == SessionResult.CONNECTION_ERROR && client.isWaitingForServer())
+                        || (options.isWaitForRoom() && result == SessionResult.ROOM_FULL)) {
+                    long waited = TimeUnit.NANOSECONDS.toSeconds(System.nanoTime() - waitStart);
+                    if (options.getReconnectBudgetSeconds() > 0 && waited + backoffSeconds > options.getReconnectBudgetSeconds()) {
+                        System.out.println(I18n.t("wait.budget_exhausted", options.getReconnectBudgetSeconds()));
+                        break;
+                    }
+                    System.out.println(I18n.t(result == SessionResult.ROOM_FULL ? "wait.room_full" : "wait.server_unavailable", backoffSeconds));
+                    TimeUnit.SECONDS.sleep(backoffSeconds);
+                    backoffSeconds = Math.min(backoffSeconds * 2, MAX_WAIT_BACKOFF_SECONDS);
+                    result = client.startChat(sender, roomId);
+                }
+                while (result == SessionResult.RECONNECT) {
+                    result = client.startChat(sender, roomId);
+                }
                 if (result == SessionResult.QUIT_APPLICATION) {
                     break;
                 }
+                if (result == SessionResult.ROOM_FULL) {
+                    exitCode = EXIT_ROOM_FULL;
+                    break;
+                }
                 // If NORMAL_LEAVE or CONNECTION_ERROR, the loop continues, allowing to join another room
             } catch (InterruptedException e) {
-                System.err.println("Chat interrumpido: " + e.getMessage());
+                System.err.println(I18n.t("app.interrupted", e.getMessage()));
                 break;
             }
         }
         
-        System.out.println("Cerrando conexión...");
+        ConsoleOutput.flush();
+        System.out.println(I18n.t("app.closing"));
         client.shutdown();
-        System.out.println("¡Adiós!");
+        if (recorder != null) {
+            try { recorder.close(); } catch (IOException e) { /* Every line was already flushed */ }
+        }
+        if (!options.isNoSummary()) {
+            System.out.println(client.stats.formatSummary());
+        }
+        System.out.println(I18n.t("app.goodbye"));
+        if (exitCode != 0) {
+            System.exit(exitCode);
+        }
     }
 }
@@ -0,0 +1,36 @@
+package com.conference.client;
+
+/**
+ * Room events for programs that embed ChatClient and want to react to them without
+ * parsing terminal output. Register with ChatClient.addEventListener; every method
+ * has an empty default so listeners only override what they need.
+ *
+ * Ordering: events are delivered synchronously on the gRPC callback thread, in the
+ * order the server sent them. For each join, onConnected comes before any other event
+ * of that session and onDisconnected is the last one, except that a microphone still
+ * on when the session ends is reported off right after it; a join the server rejects
+ * produces neither. Members the server announces before accepting the join are
+ * reported with onUserJoined right after onConnected. onAudioChanged is delivered on
+ * the thread that turned the microphone on or off.
+ */
+public interface ClientEventListener {
+
+    default void onConnected(String sender, String roomId) {}
+
+    default void onDisconnected(String roomId) {}
+
+    default void onMessageReceived(MessageHistory.Entry message) {}
+
+    /** A chat message this client sent, delivered on the input thread. */
+    default void onMessageSent(MessageHistory.Entry message) {}
+
+    /** A command line typed by the user, after alias expansion, delivered on the input thread. */
+    default void onCommand(String commandLine) {}
+
+    default void onUserJoined(String name) {}
+
+    default void onUserLeft(String name) {}
+
+    /** The microphone started or stopped transmitting to the room. */
+    default void onAudioChanged(boolean transmitting) {}
+}
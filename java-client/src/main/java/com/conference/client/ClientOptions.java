@@ -0,0 +1,541 @@
+package com.conference.client;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.util.Locale;
+import java.util.function.Supplier;
+
+/**
+ * Command-line options for the Java client. Every option has a default so the
+ * client keeps working when launched without arguments.
+ */
+public class ClientOptions {
+
+    public static final int DEFAULT_JOIN_TIMEOUT_SECONDS = 10;
+    public static final int DEFAULT_MAX_RECV_SIZE = 4 * 1024 * 1024; // gRPC's own default
+    public static final int DEFAULT_DEDUPE_WINDOW = 256;
+    public static final int DEFAULT_MONITOR_DELAY_MS = 50;
+    public static final int DEFAULT_MAX_AUDIO_CHUNK_BYTES = 64 * 1024; // Captured chunks are 1 KiB
+    public static final String DEFAULT_HOST = "localhost";
+    public static final int DEFAULT_PORT = 50051;
+
+    private int joinTimeoutSeconds = DEFAULT_JOIN_TIMEOUT_SECONDS;
+    private int maxRecvSize = DEFAULT_MAX_RECV_SIZE;
+    private int dedupeWindow = DEFAULT_DEDUPE_WINDOW;
+    private int maxAudioChunkBytes = DEFAULT_MAX_AUDIO_CHUNK_BYTES;
+    private boolean plain = false;
+    private int renderCoalesceMillis = 0;
+    private boolean noRedraw = false;
+    private boolean audioOnly = false;
+    private int controlPort = 0;
+    private boolean dumpProto = false; // Hidden developer flag, not in the usage text
+    private MentionAlert.BellMode bellMode = MentionAlert.BellMode.OFF;
+    private I18n.Lang lang = null; // null: taken from LANG
+    private int audioChannels = 1;
+    private int monitorDelayMs = DEFAULT_MONITOR_DELAY_MS;
+    private boolean alert = false;
+    private String mentionSound = null;
+    private int statsIntervalSeconds = 0;
+    private boolean echoSelf = false;
+    private String micPriority = null;
+    private String speakerPriority = null;
+    private boolean markdown = false;
+    private boolean verbose = false;
+    private boolean quiet = false;
+    private boolean waitForServer = false;
+    private boolean waitForRoom = false;
+    private int reconnectBudgetSeconds = 0;
+    private boolean readOnly = false;
+    private boolean noSummary = false;
+    private boolean rosterCache = false;
+    private final CommandAliases aliases = new CommandAliases();
+    private boolean check = false;
+    private String replayPath = null;
+    private int replaySpeed = 1;
+    private String recordSessionPath = null;
+    private String host = null;
+    private int port = DEFAULT_PORT;
+    private String name = null;
+    private String room = null;
+    private String inviteName = null;
+    private boolean roomFromHostname = false;
+    private int scrollback = MessageHistory.DEFAULT_CAPACITY;
+
+    public static ClientOptions parse(String[] args) {
+        ClientOptions options = new ClientOptions();
+        for (int i = 0; i < args.length; i++) {
+            switch (args[i]) {
+                case "check":
+                    if (i != 0) throw new IllegalArgumentException(I18n.t("options.check_first"));
+                    options.check = true;
+                    break;
+                case "replay":
+                    if (i != 0) throw new IllegalArgumentException(I18n.t("options.replay_first"));
+                    options.replayPath = requireValue(args, ++i, "replay");
+                    break;
+                case "--replay-speed":
+                    options.replaySpeed = parseInt(args, ++i, "--replay-speed", 0);
+                    break;
+                case "--record-session":
+                    options.recordSessionPath = requireValue(args, ++i, "--record-session");
+                    break;
+                case "--server":
+                    options.parseServer(requireValue(args, ++i, "--server"));
+                    break;
+                case "--join-timeout":
+                    options.joinTimeoutSeconds = parseInt(args, ++i, "--join-timeout", 1);
+                    break;
+                case "--max-recv-size":
+                    options.maxRecvSize = parseInt(args, ++i, "--max-recv-size", 1);
+                    break;
+                case "--dedupe-window":
+                    options.dedupeWindow = parseInt(args, ++i, "--dedupe-window", 0);
+                    break;
+                case "--max-audio-chunk-bytes":
+                    options.maxAudioChunkBytes = parseInt(args, ++i, "--max-audio-chunk-bytes", 1);
+                    break;
+                case "--audio-channels":
+                    options.audioChannels = parseInt(args, ++i, "--audio-channels", 1);
+                    if (options.audioChannels > 2) {
+                        throw new IllegalArgumentException(I18n.t("options.audio_channels_invalid"));
+                    }
+                    break;
+                case "--monitor-delay-ms":
+                    options.monitorDelayMs = parseInt(args, ++i, "--monitor-delay-ms", 0);
+                    if (options.monitorDelayMs > 1000) {
+                        throw new IllegalArgumentException(I18n.t("options.monitor_delay_invalid"));
+                    }
+                    break;
+                case "--alert":
+                    options.alert = true;
+                    break;
+                case "--bell-on-message":
+                    options.bellMode = MentionAlert.BellMode.parse(requireValue(args, ++i, "--bell-on-message"));
+                    if (options.bellMode == null) {
+                        throw new IllegalArgumentException(I18n.t("options.bell_invalid"));
+                    }
+                    break;
+                case "--mention-sound":
+                    options.mentionSound = requireValue(args, ++i, "--mention-sound");
+                    break;
+                case "--stats-interval":
+                    options.statsIntervalSeconds = parseInt(args, ++i, "--stats-interval", 0);
+                    break;
+                case "--echo-self":
+                    options.echoSelf = true;
+                    break;
+                case "--mic-priority":
+                    options.micPriority = requireValue(args, ++i, "--mic-priority");
+                    break;
+                case "--speaker-priority":
+                    options.speakerPriority = requireValue(args, ++i, "--speaker-priority");
+                    break;
+                case "--markdown":
+                    options.markdown = true;
+                    break;
+                case "--verbose":
+                    options.verbose = true;
+                    break;
+                case "--quiet":
+                    options.quiet = true;
+                    break;
+                case "--wait-for-server":
+                    options.waitForServer = true;
+                    break;
+                case "--wait-for-room":
+                    options.waitForRoom = true;
+                    break;
+                case "--reconnect-budget":
+                    options.reconnectBudgetSeconds = parseInt(args, ++i, "--reconnect-budget", 0);
+                    break;
+                case "--read-only":
+                    options.readOnly = true;
+                    break;
+                case "--roster-cache":
+                    options.rosterCache = true;
+                    break;
+                case "--no-summary":
+                    options.noSummary = true;
+                    break;
+                case "--alias":
+                    options.aliases.define(requireValue(args, ++i, "--alias"));
+                    break;
+                case "--lang":
+                    options.lang = I18n.Lang.parse(requireValue(args, ++i, "--lang"));
+                    if (options.lang == null) {
+                        throw new IllegalArgumentException(I18n.t("options.lang_invalid"));
+                    }
+                    break;
+                case "--dump-proto":
+                    options.dumpProto = true;
+                    break;
+                case "--control-port":
+                    options.controlPort = parseInt(args, ++i, "--control-port", 1);
+                    if (options.controlPort > 65535) {
+                        throw new IllegalArgumentException(I18n.t("options.control_port_invalid"));
+                    }
+                    break;
+                case "--audio-only":
+                    options.audioOnly = true;
+                    break;
+                case "--no-redraw":
+                    options.noRedraw = true;
+                    break;
+                case "--render-coalesce-ms":
+                    options.renderCoalesceMillis = parseInt(args, ++i, "--render-coalesce-ms", 0);
+                    if (options.renderCoalesceMillis > 1000) {
+                        throw new IllegalArgumentException(I18n.t("options.coalesce_invalid"));
+                    }
+                    break;
+                case "--plain":
+                    options.plain = true;
+                    break;
+                case "--scrollback":
+                    options.scrollback = parseInt(args, ++i, "--scrollback", 1);
+                    break;
+                case "--room-from-hostname":
+                    options.roomFromHostname = true;
+                    break;
+                default:
+                    if (args[i].startsWith("-")) {
+                        throw new IllegalArgumentException(I18n.t("options.unknown", args[i]));
+                    }
+                    if (RoomInvite.isInvite(args[i])) {
+                        options.applyInvite(RoomInvite.parse(args[i]));
+                    } else {
+                        options.addPositional(args[i]);
+                    }
+            }
+        }
+        options.aliases.validate();
+        if (options.name == null) options.name = options.inviteName;
+        if (options.room == null) {
+            options.room = defaultRoom(System.getenv("CHAT_ROOM"), options.roomFromHostname, ClientOptions::localHostname);
+        }
+        return options;
+    }
+
+    /**
+     * Room for the first join when none was given on the command line: derived from the
+     * host name with --room-from-hostname, else CHAT_ROOM, else null (asked as usual).
+     */
+    static String defaultRoom(String envRoom, boolean fromHostname, Supplier<String> hostname) {
+        if (fromHostname) {
+            String host = hostname.get();
+            String room = host == null ? null : roomFromHostname(host);
+            if (room == null) {
+                throw new IllegalArgumentException(I18n.t("options.hostname_room", host));
+            }
+            return room;
+        }
+        if (envRoom == null || envRoom.trim().isEmpty()) return null;
+        if (envRoom.trim().chars().anyMatch(c -> Character.isWhitespace(c) || Character.isISOControl(c))) {
+            throw new IllegalArgumentException(I18n.t("options.env_room", envRoom));
+        }
+        return envRoom.trim();
+    }
+
+    /**
+     * Room id for a machine: the first label of its host name in lower case, keeping only
+     * letters, digits, '-' and '_' ("LAB-PC07.elo.local" gives "lab-pc07"). Null if nothing is left.
+     */
+    static String roomFromHostname(String hostname) {
+        String label = hostname.trim().split("\\.", 2)[0].toLowerCase(Locale.ROOT);
+        String room = label.replaceAll("[^a-z0-9_-]", "");
+        return room.isEmpty() ? null : room;
+    }
+
+    private static String localHostname() {
+        try {
+            return InetAddress.getLocalHost().getHostName();
+        } catch (UnknownHostException e) {
+            String env = System.getenv("HOSTNAME");
+            return env != null ? env : System.getenv("COMPUTERNAME");
+        }
+    }
+
+    // Positional arguments are, in order, the user name and the room.
+    private void addPositional(String value) {
+        if (value.trim().isEmpty()) {
+            throw new IllegalArgumentException(I18n.t(name == null ? "options.empty_name" : "options.empty_room"));
+        }
+        if (name == null) {
+            name = value.trim();
+        } else if (room == null) {
+            room = value.trim();
+        } else {
+            throw new IllegalArgumentException(I18n.t("options.unexpected", value));
+        }
+    }
+
+    // An invite link sets server and room; its suggested name is used only if no positional
+    // name is given, before or after the link.
+    private void applyInvite(RoomInvite invite) {
+        if (room != null) {
+            throw new IllegalArgumentException(I18n.t("options.room_twice", invite.toUri()));
+        }
+        host = invite.getHost();
+        port = invite.getPort();
+        room = invite.getRoom();
+        inviteName = invite.getName();
+    }
+
+    /** "host:port" for display, with IPv6 literals bracketed as --server expects them. */
+    static String formatServer(String host, int port) {
+        return (host.contains(":") ? "[" + host + "]" : host) + ":" + port;
+    }
+
+    // Accepts "host", "host:port", "[ipv6]" or "[ipv6]:port". A bare IPv6 literal is rejected
+    // because its last group could not be told apart from a port.
+    private void parseServer(String value) {
+        String portText = null;
+        if (value.startsWith("[")) {
+            int close = value.indexOf(']');
+            if (close < 0) throw new IllegalArgumentException(I18n.t("options.server_bracket", value));
+            host = value.substring(1, close);
+            String rest = value.substring(close + 1);
+            if (!rest.isEmpty()) {
+                if (!rest.startsWith(":")) throw new IllegalArgumentException(I18n.t("options.server_port_after_bracket", value));
+                portText = rest.substring(1);
+            }
+        } else if (value.indexOf(':') != value.lastIndexOf(':')) {
+            throw new IllegalArgumentException(I18n.t("options.server_ipv6", DEFAULT_PORT, value));
+        } else {
+            int colon = value.indexOf(':');
+            host = colon < 0 ? value : value.substring(0, colon);
+            if (colon >= 0) portText = value.substring(colon + 1);
+        }
+        if (host.isEmpty()) throw new IllegalArgumentException(I18n.t("options.server_host", value));
+        if (portText == null) return;
+        try {
+            port = Integer.parseInt(portText);
+        } catch (NumberFormatException e) {
+            throw new IllegalArgumentException(I18n.t("options.server_port", value));
+        }
+        if (port < 1 || port > 65535) throw new IllegalArgumentException(I18n.t("options.server_port_range", value));
+    }
+
+    private static String requireValue(String[] args, int index, String option) {
+        if (index >= args.length) {
+            throw new IllegalArgumentException(I18n.t("options.missing_value", option));
+        }
+        return args[index];
+    }
+
+    private static int parseInt(String[] args, int index, String option, int min) {
+        String value = requireValue(args, index, option);
+        try {
+            int parsed = Integer.parseInt(value);
+            if (parsed < min) throw new NumberFormatException();
+            return parsed;
+        } catch (NumberFormatException e) {
+            throw new IllegalArgumentException(I18n.t("options.invalid_value", option, value));
+        }
+    }
+
+    public static void printUsage() {
+        System.out.println(I18n.t("options.usage"));
+        System.out.println(I18n.t("options.usage_replay"));
+        System.out.println(I18n.t("options.positional"));
+        System.out.println(I18n.t("options.invite"));
+        System.out.println(I18n.t("options.room_from_hostname"));
+        System.out.println(I18n.t("options.check"));
+        System.out.println(I18n.t("options.replay"));
+        System.out.println(I18n.t("options.replay_speed"));
+        System.out.println(I18n.t("options.record_session"));
+        System.out.println(I18n.t("options.server", DEFAULT_PORT));
+        System.out.println(I18n.t("options.join_timeout", DEFAULT_JOIN_TIMEOUT_SECONDS));
+        System.out.println(I18n.t("options.max_recv_size", DEFAULT_MAX_RECV_SIZE));
+        System.out.println(I18n.t("options.dedupe_window", DEFAULT_DEDUPE_WINDOW));
+        System.out.println(I18n.t("options.wait_for_server"));
+        System.out.println(I18n.t("options.wait_for_room"));
+        System.out.println(I18n.t("options.reconnect_budget"));
+        System.out.println(I18n.t("options.read_only"));
+        System.out.println(I18n.t("options.roster_cache"));
+        System.out.println(I18n.t("options.no_summary"));
+        System.out.println(I18n.t("options.alias"));
+        System.out.println(I18n.t("options.plain"));
+        System.out.println(I18n.t("options.scrollback", MessageHistory.DEFAULT_CAPACITY));
+        System.out.println(I18n.t("options.max_audio_chunk_bytes", DEFAULT_MAX_AUDIO_CHUNK_BYTES));
+        System.out.println(I18n.t("options.control_port"));
+        System.out.println(I18n.t("options.audio_only"));
+        System.out.println(I18n.t("options.lang"));
+        System.out.println(I18n.t("options.no_redraw"));
+        System.out.println(I18n.t("options.render_coalesce"));
+        System.out.println(I18n.t("options.audio_channels"));
+        System.out.println(I18n.t("options.monitor_delay", DEFAULT_MONITOR_DELAY_MS));
+        System.out.println(I18n.t("options.alert"));
+        System.out.println(I18n.t("options.mention_sound"));
+        System.out.println(I18n.t("options.bell_on_message"));
+        System.out.println(I18n.t("options.stats_interval"));
+        System.out.println(I18n.t("options.echo_self"));
+        System.out.println(I18n.t("options.mic_priority"));
+        System.out.println(I18n.t("options.speaker_priority"));
+        System.out.println(I18n.t("options.markdown"));
+        System.out.println(I18n.t("options.verbose"));
+        System.out.println(I18n.t("options.quiet"));
+    }
+
+    public boolean isCheck() {
+        return check;
+    }
+
+    /** File given to the replay subcommand, or null when not replaying. */
+    public String getReplayPath() {
+        return replayPath;
+    }
+
+    public int getReplaySpeed() {
+        return replaySpeed;
+    }
+
+    public String getRecordSessionPath() {
+        return recordSessionPath;
+    }
+
+    public boolean hasServer() {
+        return host != null;
+    }
+
+    public String getHost() {
+        return host != null ? host : DEFAULT_HOST;
+    }
+
+    public int getPort() {
+        return port;
+    }
+
+    /** User name given on the command line, or null to prompt for it. */
+    public String getName() {
+        return name;
+    }
+
+    /** Room given on the command line, or null to prompt for it. */
+    public String getRoom() {
+        return room;
+    }
+
+    public int getScrollback() {
+        return scrollback;
+    }
+
+    public int getJoinTimeoutSeconds() {
+        return joinTimeoutSeconds;
+    }
+
+    public int getMaxRecvSize() {
+        return maxRecvSize;
+    }
+
+    public int getDedupeWindow() {
+        return dedupeWindow;
+    }
+
+    public int getMaxAudioChunkBytes() {
+        return maxAudioChunkBytes;
+    }
+
+    public boolean isWaitForServer() {
+        return waitForServer;
+    }
+
+    public boolean isWaitForRoom() {
+        return waitForRoom;
+    }
+
+    public int getReconnectBudgetSeconds() {
+        return reconnectBudgetSeconds;
+    }
+
+    public boolean isReadOnly() {
+        return readOnly;
+    }
+
+    public boolean isRosterCache() {
+        return rosterCache;
+    }
+
+    public boolean isNoSummary() {
+        return noSummary;
+    }
+
+    public CommandAliases getAliases() {
+        return aliases;
+    }
+
+    public boolean isPlain() {
+        return plain;
+    }
+
+    public I18n.Lang getLang() {
+        return lang;
+    }
+
+    public MentionAlert.BellMode getBellMode() {
+        return bellMode;
+    }
+
+    public boolean isDumpProto() {
+        return dumpProto;
+    }
+
+    public int getControlPort() {
+        return controlPort;
+    }
+
+    public boolean isAudioOnly() {
+        return audioOnly;
+    }
+
+    public boolean isNoRedraw() {
+        return noRedraw;
+    }
+
+    public int getRenderCoalesceMillis() {
+        return renderCoalesceMillis;
+    }
+
+    public int getAudioChannels() {
+        return audioChannels;
+    }
+
+    public int getMonitorDelayMs() {
+        return monitorDelayMs;
+    }
+
+    public boolean isAlert() {
+        return alert;
+    }
+
+    public String getMentionSound() {
+        return mentionSound;
+    }
+
+    public int getStatsIntervalSeconds() {
+        return statsIntervalSeconds;
+    }
+
+    public boolean isEchoSelf() {
+        return echoSelf;
+    }
+
+    public String getMicPriority() {
+        return micPriority;
+    }
+
+    public String getSpeakerPriority() {
+        return speakerPriority;
+    }
+
+    public boolean isMarkdown() {
+        return markdown;
+    }
+
+    public boolean isVerbose() {
+        return verbose;
+    }
+
+    public boolean isQuiet() {
+        return quiet;
+    }
+}
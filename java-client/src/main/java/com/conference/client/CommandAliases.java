@@ -0,0 +1,87 @@
+package com.conference.client;
+
+import java.util.HashSet;
+import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
+import java.util.Map;
+import java.util.Set;
+
+/**
+ * Short names for commands, expanded before dispatch. Built-in aliases cover the
+ * most common commands; --alias adds or replaces aliases, but never a real command
+ * name, so /quit and friends always keep their meaning.
+ */
+public class CommandAliases {
+
+    private static final Map<String, String> BUILT_IN = new LinkedHashMap<>();
+    static {
+        BUILT_IN.put("/q", "/quit");
+        BUILT_IN.put("/h", "/help");
+        BUILT_IN.put("/m", "/mic on");
+        BUILT_IN.put("/r", "/reply");
+    }
+
+    private final Map<String, String> aliases = new LinkedHashMap<>(BUILT_IN);
+    private final Set<String> userDefined = new HashSet<>();
+
+    /** Parses a --alias value of the form "/name=/command [args]". */
+    public void define(String definition) {
+        int eq = definition.indexOf('=');
+        if (eq < 0) {
+            throw new IllegalArgumentException("Alias inválido, se espera /alias=/comando: " + definition);
+        }
+        String name = definition.substring(0, eq).trim().toLowerCase();
+        String expansion = definition.substring(eq + 1).trim();
+        if (!name.startsWith("/") || name.length() < 2 || name.contains(" ")) {
+            throw new IllegalArgumentException("El alias debe ser una palabra que empiece con '/': " + definition);
+        }
+        if (!expansion.startsWith("/")) {
+            throw new IllegalArgumentException("El alias " + name + " debe apuntar a un comando: " + definition);
+        }
+        if (ChatClient.COMMANDS.contains(name)) {
+            throw new IllegalArgumentException("No se puede redefinir el comando " + name);
+        }
+        if (!userDefined.add(name)) {
+            throw new IllegalArgumentException("Alias definido dos veces: " + name);
+        }
+        aliases.put(name, expansion);
+    }
+
+    /** Checks that every alias ends in a real command; called once all aliases are defined. */
+    public void validate() {
+        for (String name : aliases.keySet()) {
+            Set<String> seen = new LinkedHashSet<>();
+            String current = name;
+            while (aliases.containsKey(current)) {
+                if (!seen.add(current)) {
+                    throw new IllegalArgumentException("Ciclo de alias: " + String.join(" → ", seen) + " → " + current);
+                }
+                current = firstWord(aliases.get(current));
+            }
+            if (!ChatClient.COMMANDS.contains(current)) {
+                throw new IllegalArgumentException("El alias " + name + " apunta a un comando desconocido: " + current);
+            }
+        }
+    }
+
+    /** Replaces a leading alias with its expansion, keeping any arguments typed after it. */
+    public String expand(String commandLine) {
+        String line = commandLine;
+        for (int depth = 0; depth <= aliases.size(); depth++) {
+            String word = firstWord(line).toLowerCase();
+            String expansion = aliases.get(word);
+            if (expansion == null) break;
+            line = expansion + line.substring(firstWord(line).length());
+        }
+        return line;
+    }
+
+    public Map<String, String> all() {
+        return new LinkedHashMap<>(aliases);
+    }
+
+    private static String firstWord(String text) {
+        int space = text.indexOf(' ');
+        return space < 0 ? text : text.substring(0, space);
+    }
+}
@@ -0,0 +1,94 @@
+package com.conference.client;
+
+import com.conference.grpc.*;
+import io.grpc.ManagedChannel;
+import io.grpc.ManagedChannelBuilder;
+import io.grpc.stub.StreamObserver;
+
+import java.util.UUID;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
+import java.util.concurrent.atomic.AtomicReference;
+
+/**
+ * Non-interactive health check: joins a throwaway room under a throwaway name, waits
+ * for the server's WELCOME, queries the room info as a round-trip probe and leaves.
+ * Used by the `check` subcommand; the result is reported through the exit code.
+ */
+public class ConnectivityCheck {
+
+    public static int run(String host, int port, int timeoutSeconds) {
+        String id = UUID.randomUUID().toString().substring(0, 8);
+        String roomId = "check-" + id;
+        String sender = "check-" + id;
+        ManagedChannel channel = ManagedChannelBuilder.forAddress(host, port).usePlaintext().build();
+        System.out.println(I18n.t("check.start", ClientOptions.formatServer(host, port)));
+        try {
+            long start = System.nanoTime();
+            CountDownLatch welcomeLatch = new CountDownLatch(1);
+            AtomicReference<String> failure = new AtomicReference<>();
+            StreamObserver<ConferenceData> requestObserver = ConferenceServiceGrpc.newStub(channel)
+                    .joinConference(joinObserver(welcomeLatch, failure));
+            requestObserver.onNext(ConferenceData.newBuilder().setSender(sender).setRoomId(roomId)
+                    .setCommand(Command.newBuilder().setType("JOIN").build()).build());
+
+            if (!welcomeLatch.await(timeoutSeconds, TimeUnit.SECONDS)) {
+                System.out.println(I18n.t("check.timeout", timeoutSeconds));
+                requestObserver.onError(new RuntimeException("check timeout"));
+                return 1;
+            }
+            if (failure.get() != null) {
+                System.out.println(I18n.t("check.join_failed", failure.get()));
+                return 1;
+            }
+            long joinMillis = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - start);
+            System.out.println(I18n.t("check.joined", joinMillis));
+
+            long probeStart = System.nanoTime();
+            ConferenceServiceGrpc.newBlockingStub(channel).withDeadlineAfter(timeoutSeconds, TimeUnit.SECONDS)
+                    .getRoomInfo(RoomInfoRequest.newBuilder().setRoomId(roomId).build());
+            long probeMillis = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - probeStart);
+            System.out.println(I18n.t("check.round_trip", probeMillis));
+
+            requestObserver.onCompleted();
+            return 0;
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return 1;
+        } catch (RuntimeException e) {
+            System.out.println(I18n.t("check.error", e.getMessage()));
+            return 1;
+        } finally {
+            channel.shutdownNow();
+        }
+    }
+
+    /**
+     * Releases the latch on WELCOME, or on any outcome before it: an ERROR command, a
+     * stream error or the server closing the stream. Those set the failure reason.
+     */
+    static StreamObserver<ConferenceData> joinObserver(CountDownLatch welcomeLatch, AtomicReference<String> failure) {
+        return new StreamObserver<ConferenceData>() {
+            @Override
+            public void onNext(ConferenceData data) {
+                if (data.getPayloadCase() != ConferenceData.PayloadCase.COMMAND) return;
+                if (data.getCommand().getType().equals("WELCOME")) welcomeLatch.countDown();
+                else if (data.getCommand().getType().equals("ERROR")) fail(data.getCommand().getValue());
+            }
+            @Override
+            public void onError(Throwable t) {
+                fail(t.getMessage());
+            }
+            @Override
+            public void onCompleted() {
+                fail(I18n.t("check.closed"));
+            }
+            // Only the first outcome counts; anything after WELCOME is ignored.
+            private void fail(String reason) {
+                if (welcomeLatch.getCount() == 0) return;
+                failure.compareAndSet(null, reason);
+                welcomeLatch.countDown();
+            }
+        };
+    }
+}
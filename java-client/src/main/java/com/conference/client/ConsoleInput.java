@@ -0,0 +1,59 @@
+package com.conference.client;
+
+import java.io.BufferedReader;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.InputStreamReader;
+import java.nio.charset.StandardCharsets;
+import java.util.concurrent.BlockingQueue;
+import java.util.concurrent.LinkedBlockingQueue;
+
+/**
+ * Reads stdin on a single daemon thread for the whole process and hands lines out
+ * through a queue. Sessions come and go (leave, reconnect, join another room) and
+ * simply stop taking from the queue; no reader thread is left blocked on a stale
+ * session, and waiting for a line can be interrupted.
+ */
+public class ConsoleInput {
+
+    // Identity-compared sentinel marking end of input.
+    @SuppressWarnings("StringOperationCanBeSimplified")
+    private static final String END_OF_INPUT = new String("");
+
+    private final BlockingQueue<String> lines = new LinkedBlockingQueue<>();
+
+    public ConsoleInput(InputStream in) {
+        // Decodes as UTF-8 regardless of platform default; invalid byte sequences
+        // (binary garbage, truncated characters) become U+FFFD instead of failing the read.
+        BufferedReader reader = new BufferedReader(new InputStreamReader(in, StandardCharsets.UTF_8));
+        Thread thread = new Thread(() -> {
+            try {
+                String line;
+                while ((line = reader.readLine()) != null) {
+                    lines.add(line);
+                }
+            } catch (IOException e) {
+                // Treat a broken stdin like end of input
+            }
+            lines.add(END_OF_INPUT);
+        }, "stdin-reader");
+        thread.setDaemon(true);
+        thread.start();
+    }
+
+    /** Queues a line as if it had been typed; used by ControlServer. */
+    public void feed(String line) {
+        lines.add(line);
+    }
+
+    /** Blocks until the next line is available; returns null once stdin is exhausted. */
+    public String readLine() throws InterruptedException {
+        String line = lines.take();
+        if (line == END_OF_INPUT) {
+            lines.add(END_OF_INPUT); // Keep reporting end of input to later readers
+            return null;
+        }
+        ConsoleOutput.lineEntered();
+        return line;
+    }
+}
@@ -0,0 +1,246 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.util.concurrent.Executors;
+import java.util.concurrent.ScheduledExecutorService;
+import java.util.concurrent.TimeUnit;
+
+/**
+ * Single owner of terminal output. In interactive mode each message clears the
+ * current prompt line before printing and the prompt is redrawn afterwards. In
+ * plain mode (stdout is not a terminal, or --plain) messages are printed one per
+ * line with no cursor control and no prompt, so redirecting to a file stays clean.
+ *
+ * With a coalescing window (--render-coalesce-ms), interactive messages arriving
+ * within the window are rendered together: one line clear, the messages, the
+ * latest prompt and a single flush.
+ *
+ * No-redraw mode (--no-redraw, or TERM=dumb) is for terminals that render the line
+ * clear badly: messages are appended with no escape sequences at all, and the prompt
+ * is printed once per entered line instead of after every message.
+ *
+ * PrintStream swallows write errors, so a closed stdout (piping into head, say) would
+ * otherwise leave the client running blind. Every write is checked and the handler
+ * set with onClosed runs once, on its own thread, when stdout is gone.
+ */
+public final class ConsoleOutput {
+
+    private static final String CLEAR_LINE = "\r\u001b[2K";
+    private static final String HIGHLIGHT = "\u001b[1;33m";
+    private static final String RESET = "\u001b[0m";
+
+    private static volatile boolean plain = !stdoutIsTerminal();
+    private static volatile boolean noRedraw = "dumb".equals(System.getenv("TERM"));
+    private static boolean promptShown = false;
+    private static long coalesceMillis = 0;
+    private static ScheduledExecutorService renderer;
+    private static final StringBuilder pending = new StringBuilder();
+    private static String pendingPrompt = null;
+    private static boolean flushScheduled = false;
+    private static Runnable closedHandler = null;
+    private static boolean closed = false;
+
+    private ConsoleOutput() {}
+
+    /**
+     * Whether stdout is a terminal. Java 11 has no isatty, and System.console() is also
+     * null when only stdin is redirected, so on Linux the target of /proc/self/fd/1 is
+     * checked instead (a pts or tty device). Elsewhere the console check is the fallback.
+     */
+    static boolean stdoutIsTerminal() {
+        Path fd = Paths.get("/proc/self/fd/1");
+        if (Files.isSymbolicLink(fd)) {
+            try {
+                String target = Files.readSymbolicLink(fd).toString();
+                return target.startsWith("/dev/pts/") || target.startsWith("/dev/tty") || target.equals("/dev/console");
+            } catch (IOException | SecurityException e) {
+                // fall through to the console check
+            }
+        }
+        return System.console() != null;
+    }
+
+    public static void setPlain(boolean value) {
+        plain = value;
+    }
+
+    public static boolean isPlain() {
+        return plain;
+    }
+
+    public static void setNoRedraw(boolean value) {
+        noRedraw = value;
+    }
+
+    public static boolean isNoRedraw() {
+        return noRedraw;
+    }
+
+    /** Whether styling escape sequences (highlight, markdown) may be written. */
+    public static boolean usesAnsi() {
+        return !plain && !noRedraw;
+    }
+
+    /** Handler run once when stdout can no longer be written, e.g. a broken pipe. */
+    public static synchronized void onClosed(Runnable handler) {
+        closedHandler = handler;
+    }
+
+    /** Sets the coalescing window; 0 renders every message immediately. */
+    public static synchronized void setCoalesceMillis(long millis) {
+        coalesceMillis = millis;
+        if (millis > 0 && renderer == null) {
+            renderer = Executors.newSingleThreadScheduledExecutor(r -> {
+                Thread thread = new Thread(r, "console-render");
+                thread.setDaemon(true);
+                return thread;
+            });
+        }
+    }
+
+    /**
+     * Peer-supplied text made safe to print: ANSI CSI sequences, control characters and
+     * bidirectional overrides are dropped, and text longer than maxLength
+     * code points is cut with an ellipsis.
+     */
+    public static String sanitize(String text, int maxLength) {
+        StringBuilder sb = new StringBuilder();
+        int kept = 0;
+        for (int i = 0; i < text.length(); ) {
+            int cp = text.codePointAt(i);
+            i += Character.charCount(cp);
+            if (cp == 0x1B && i < text.length() && text.charAt(i) == '[') {
+                // Drop the whole CSI sequence, not just its ESC: parameters up to the final byte
+                i++;
+                while (i < text.length() && (text.charAt(i) < 0x40 || text.charAt(i) > 0x7E)) i++;
+                i++;
+                continue;
+            }
+            if (Character.isISOControl(cp) || (cp >= 0x202A && cp <= 0x202E) || (cp >= 0x2066 && cp <= 0x2069)) continue;
+            if (kept == maxLength) {
+                sb.setLength(sb.length() - Character.charCount(sb.codePointBefore(sb.length())));
+                return sb.append('…').toString();
+            }
+            sb.appendCodePoint(cp);
+            kept++;
+        }
+        return sb.toString();
+    }
+
+    /** Message text made safe to print like sanitize, keeping its line breaks and turning tabs into spaces. */
+    public static String sanitizeText(String text) {
+        String[] lines = text.replace("\r\n", "\n").replace('\t', ' ').split("\n", -1);
+        StringBuilder sb = new StringBuilder();
+        for (int i = 0; i < lines.length; i++) {
+            if (i > 0) sb.append('\n');
+            sb.append(sanitize(lines[i], Integer.MAX_VALUE));
+        }
+        return sb.toString();
+    }
+
+    // Bold yellow in interactive mode; plain mode leaves the text untouched.
+    public static String highlight(String text) {
+        return usesAnsi() ? HIGHLIGHT + text + RESET : text;
+    }
+
+    public static synchronized void printLine(String message) {
+        if (plain || noRedraw) {
+            System.out.println(message);
+            checkClosed();
+            return;
+        }
+        if (coalesceMillis > 0) {
+            pending.append(message).append(System.lineSeparator());
+            scheduleFlush();
+            return;
+        }
+        System.out.print(CLEAR_LINE);
+        System.out.println(message);
+        checkClosed();
+    }
+
+    public static synchronized void printPrompt(String prompt) {
+        if (plain) return;
+        if (noRedraw) {
+            if (promptShown) return;
+            promptShown = true;
+            System.out.print(prompt);
+            checkClosed();
+            return;
+        }
+        if (coalesceMillis > 0 && (flushScheduled || pending.length() > 0)) {
+            pendingPrompt = prompt; // Drawn once, after the batch
+            return;
+        }
+        System.out.print(CLEAR_LINE + prompt);
+        checkClosed();
+    }
+
+    public static synchronized void printProgress(String line) {
+        if (plain || noRedraw) return;
+        flush();
+        System.out.print(CLEAR_LINE + line);
+        System.out.flush();
+    }
+
+    /** Clears the terminal before a full redraw (/scroll); does nothing without cursor control. */
+    public static synchronized void clearScreen() {
+        if (!usesAnsi()) return;
+        flush();
+        System.out.print("\u001b[2J\u001b[H");
+        checkClosed();
+    }
+
+    public static synchronized void bell() {
+        if (plain) return;
+        flush();
+        System.out.print("\u0007");
+        System.out.flush();
+    }
+
+    // Moves past a progress bar so the next message starts on a fresh line.
+    public static synchronized void endProgress() {
+        flush();
+        if (!plain && !noRedraw) System.out.println();
+    }
+
+    /** Called for every line read from the user; in no-redraw mode the next prompt may be shown. */
+    public static synchronized void lineEntered() {
+        promptShown = false;
+    }
+
+    /** Renders anything still waiting in the coalescing window. */
+    public static synchronized void flush() {
+        flushScheduled = false;
+        if (pending.length() == 0 && pendingPrompt == null) return;
+        // One clear for the whole batch: the messages start on the cleared prompt line and
+        // leave the cursor at the start of a fresh line for the prompt.
+        StringBuilder batch = new StringBuilder(CLEAR_LINE).append(pending);
+        if (pendingPrompt != null) batch.append(pendingPrompt);
+        pending.setLength(0);
+        pendingPrompt = null;
+        System.out.print(batch);
+        checkClosed();
+    }
+
+    // checkError flushes the stream and reports whether any write so far has failed.
+    private static void checkClosed() {
+        if (closed || !System.out.checkError()) return;
+        closed = true;
+        if (closedHandler != null) {
+            // Not run inline: the handler shuts down threads that may be waiting on this lock.
+            Thread thread = new Thread(closedHandler, "stdout-closed");
+            thread.setDaemon(true);
+            thread.start();
+        }
+    }
+
+    private static void scheduleFlush() {
+        if (flushScheduled) return;
+        flushScheduled = true;
+        renderer.schedule(ConsoleOutput::flush, coalesceMillis, TimeUnit.MILLISECONDS);
+    }
+}
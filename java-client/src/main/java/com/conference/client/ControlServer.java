@@ -0,0 +1,225 @@
+package com.conference.client;
+
+import java.io.BufferedReader;
+import java.io.IOException;
+import java.io.InputStreamReader;
+import java.io.OutputStreamWriter;
+import java.io.Writer;
+import java.net.InetAddress;
+import java.net.ServerSocket;
+import java.net.Socket;
+import java.nio.charset.StandardCharsets;
+import java.security.MessageDigest;
+import java.security.SecureRandom;
+import java.time.Clock;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.BlockingQueue;
+import java.util.concurrent.LinkedBlockingQueue;
+
+/**
+ * Local control channel for external programs (--control-port). Listens on 127.0.0.1
+ * only. Other local users can reach that port too, so each connection must first send
+ * the random token printed at startup; without it the connection is closed. After that
+ * it reads newline-delimited commands: say <text>, mic <on|off>, join <room> and quit.
+ * They are turned into the lines a user would type and fed into the same input queue as
+ * stdin. Client events are written back on the connection as JSON lines in the
+ * --record-session format; a malformed command gets an "error" event instead. Events
+ * are queued and written by a thread per connection, so a controller that stops
+ * reading cannot hold up chat delivery; past MAX_QUEUED_EVENTS, events are dropped.
+ */
+public class ControlServer implements ClientEventListener, AutoCloseable {
+
+    static final int MAX_QUEUED_EVENTS = 1000;
+    private static final String END_OF_EVENTS = "";
+
+    private final ServerSocket serverSocket;
+    private final String token = newToken();
+    private final ConsoleInput input;
+    private final ChatClient client;
+    private final Clock clock;
+    private volatile boolean inRoom = false;
+    private volatile String sender;
+
+    public ControlServer(int port, ConsoleInput input, ChatClient client, Clock clock) throws IOException {
+        this.serverSocket = new ServerSocket(port, 0, InetAddress.getLoopbackAddress());
+        this.input = input;
+        this.client = client;
+        this.clock = clock;
+        client.addEventListener(this);
+    }
+
+    /** Secret a controller must send as its first line. */
+    public String getToken() {
+        return token;
+    }
+
+    public int getPort() {
+        return serverSocket.getLocalPort();
+    }
+
+    private static String newToken() {
+        byte[] bytes = new byte[16];
+        new SecureRandom().nextBytes(bytes);
+        StringBuilder hex = new StringBuilder();
+        for (byte b : bytes) hex.append(String.format("%02x", b));
+        return hex.toString();
+    }
+
+    static boolean tokenMatches(String expected, String line) {
+        return line != null && MessageDigest.isEqual(expected.getBytes(StandardCharsets.UTF_8),
+                line.trim().getBytes(StandardCharsets.UTF_8));
+    }
+
+    public void start() {
+        Thread thread = new Thread(this::acceptLoop, "control-server");
+        thread.setDaemon(true);
+        thread.start();
+    }
+
+    @Override public void onConnected(String sender, String roomId) {
+        this.sender = sender;
+        inRoom = true;
+    }
+
+    @Override public void onDisconnected(String roomId) {
+        inRoom = false;
+    }
+
+    /**
+     * Input lines for a control command, given whether a room session is active and the
+     * current name (null before the first join). Null if the command is malformed.
+     */
+    static List<String> translate(String command, boolean inRoom, String sender) {
+        String[] parts = command.trim().split("\\s+", 2);
+        String arg = parts.length == 2 ? parts[1].trim() : "";
+        List<String> lines = new ArrayList<>();
+        switch (parts[0]) {
+            case "say":
+                // Text starting with "/" would run as a command
+                if (!inRoom || arg.isEmpty() || arg.startsWith("/")) return null;
+                lines.add(arg);
+                return lines;
+            case "mic":
+                if (!inRoom || !(arg.equals("on") || arg.equals("off"))) return null;
+                lines.add("/mic " + arg);
+                return lines;
+            case "join":
+                if (arg.isEmpty() || arg.contains(" ") || arg.equalsIgnoreCase("quit")) return null;
+                if (inRoom) lines.add("/leave");
+                lines.add(arg);
+                if (sender != null) lines.add(sender); // Otherwise the name is still asked on stdin
+                return lines;
+            case "quit":
+                if (!arg.isEmpty()) return null;
+                lines.add(inRoom ? "/quit" : "quit");
+                return lines;
+            default:
+                return null;
+        }
+    }
+
+    private void acceptLoop() {
+        while (!serverSocket.isClosed()) {
+            try {
+                Socket socket = serverSocket.accept();
+                Thread thread = new Thread(() -> serve(socket), "control-connection");
+                thread.setDaemon(true);
+                thread.start();
+            } catch (IOException e) {
+                return; // Closed
+            }
+        }
+    }
+
+    private void serve(Socket socket) {
+        SessionRecorder events = null;
+        EventQueue queue = null;
+        try (Socket s = socket;
+             BufferedReader reader = new BufferedReader(new InputStreamReader(s.getInputStream(), StandardCharsets.UTF_8))) {
+            queue = new EventQueue(new OutputStreamWriter(s.getOutputStream(), StandardCharsets.UTF_8));
+            events = new SessionRecorder(queue, clock);
+            if (!tokenMatches(token, reader.readLine())) {
+                events.write("error", "command", "token");
+                queue.finish(); // Sent before the socket closes
+                return;
+            }
+            client.addEventListener(events);
+            String line;
+            while ((line = reader.readLine()) != null) {
+                if (line.trim().isEmpty()) continue;
+                List<String> lines = translate(line, inRoom, sender);
+                if (lines == null) {
+                    events.write("error", "command", line);
+                    continue;
+                }
+                lines.forEach(input::feed);
+            }
+        } catch (IOException e) {
+            // Controller went away
+        } finally {
+            if (events != null) client.removeEventListener(events);
+            if (queue != null) queue.finish();
+        }
+    }
+
+    /**
+     * Writer handed to the connection's SessionRecorder: each flushed event line is queued
+     * and a dedicated thread writes it to the socket.
+     */
+    private static final class EventQueue extends Writer {
+        private final BlockingQueue<String> lines = new LinkedBlockingQueue<>(MAX_QUEUED_EVENTS);
+        private final StringBuilder current = new StringBuilder();
+        private final Thread thread;
+
+        EventQueue(Writer out) {
+            thread = new Thread(() -> drain(out), "control-events");
+            thread.setDaemon(true);
+            thread.start();
+        }
+
+        private void drain(Writer out) {
+            try (Writer w = out) {
+                for (String line = lines.take(); !line.equals(END_OF_EVENTS); line = lines.take()) {
+                    w.write(line);
+                    w.flush();
+                }
+            } catch (IOException | InterruptedException e) {
+                // Controller went away; nothing more to write
+            }
+        }
+
+        @Override public synchronized void write(char[] buf, int off, int len) {
+            current.append(buf, off, len);
+        }
+
+        @Override public synchronized void flush() {
+            if (current.length() == 0) return;
+            lines.offer(current.toString()); // Dropped when the controller is not reading
+            current.setLength(0);
+        }
+
+        @Override public void close() {
+            finish();
+        }
+
+        // Lets the thread write what is already queued, then stop; a thread still blocked
+        // after a second (full queue, stalled controller) is interrupted.
+        void finish() {
+            flush();
+            lines.offer(END_OF_EVENTS);
+            try {
+                thread.join(1000);
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            }
+            thread.interrupt();
+        }
+    }
+
+    @Override
+    public void close() throws IOException {
+        client.removeEventListener(this);
+        serverSocket.close();
+    }
+}
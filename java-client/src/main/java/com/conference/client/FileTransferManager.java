@@ -12,7 +12,7 @@ import java.io.InputStream;
 import java.nio.file.Files;
 import java.nio.file.Path;
 import java.nio.file.Paths;
-import java.time.Instant;
+import java.time.Clock;
 import java.util.UUID;
 import java.util.concurrent.atomic.AtomicBoolean;
 import java.util.concurrent.atomic.AtomicLong;
@@ -21,6 +21,7 @@ public class FileTransferManager {
     private final ConferenceServiceGrpc.ConferenceServiceStub asyncStub;
     private final StreamObserver<ConferenceData> requestObserver; // Observer for main channel
     private final String senderName;
+    private final Clock clock;
     private static final int CHUNK_SIZE = 1024 * 64; // 64KB chunks
     private static final java.time.format.DateTimeFormatter TIME_FORMATTER = java.time.format.DateTimeFormatter.ofPattern("HH:mm");
 
@@ -38,22 +39,21 @@ public class FileTransferManager {
     private final java.util.Map<String, Long> pendingBroadcasts = new java.util.concurrent.ConcurrentHashMap<>();
 
 
-    public FileTransferManager(ConferenceServiceGrpc.ConferenceServiceStub asyncStub, StreamObserver<ConferenceData> requestObserver, String senderName) {
+    public FileTransferManager(ConferenceServiceGrpc.ConferenceServiceStub asyncStub, StreamObserver<ConferenceData> requestObserver, String senderName, Clock clock) {
         this.asyncStub = asyncStub;
         this.requestObserver = requestObserver;
         this.senderName = senderName;
+        this.clock = clock;
     }
 
     // --- Message Printing ---
     private void printMessage(String message) {
-        System.out.print("\r\u001b[2K"); // Clear line
-        System.out.println(message);
+        ConsoleOutput.printLine(message);
         printPrompt();
     }
 
     private void printPrompt() {
-        System.out.print("[" + java.time.LocalDateTime.now().format(TIME_FORMATTER) + "] Tú: ");
-        System.out.flush();
+        ConsoleOutput.printPrompt("[" + java.time.LocalDateTime.now(clock).format(TIME_FORMATTER) + "] " + I18n.t("self.label") + ": ");
     }
     
     // --- Broadcast File Logic ---
@@ -128,7 +128,7 @@ public class FileTransferManager {
             FileTransferRequest request = FileTransferRequest.newBuilder()
                     .setSender(senderName).setRecipient(recipient).setRoomId(roomId)
                     .setFilename(filename).setFileSize(fileSize).setTransferId(transferId)
-                    .setTimestamp(Instant.now().getEpochSecond()).build();
+                    .setTimestamp(clock.instant().getEpochSecond()).build();
 
             asyncStub.requestFileTransfer(request, new StreamObserver<FileTransferResponse>() {
                 @Override
@@ -198,15 +198,13 @@ public class FileTransferManager {
         if (total <= 0) return;
         int percentage = (int) ((current * 100) / total);
         StringBuilder bar = new StringBuilder(60);
-        bar.append("\r\u001b[2K");
         bar.append(String.format("%s %d%% [", action, percentage));
         for (int i = 0; i < 50; i++) {
             if (i < percentage / 2) bar.append("=");
             else bar.append(" ");
         }
         bar.append("]");
-        System.out.print(bar.toString());
-        System.out.flush();
+        ConsoleOutput.printProgress(bar.toString());
     }
 
     private void startFileStreamSender(Path path, String transferId) {
@@ -217,11 +215,11 @@ public class FileTransferManager {
         StreamObserver<FileChunk> requestObserver = stubWithMetadata.transferFile(new StreamObserver<>() {
             @Override public void onNext(FileChunk v) {}
             @Override public void onError(Throwable t) {
-                System.out.println();
+                ConsoleOutput.endProgress();
                 printMessage("❌ Error durante el envío del archivo: " + t.getMessage());
             }
             @Override public void onCompleted() {
-                System.out.println();
+                ConsoleOutput.endProgress();
                 printMessage("✅ Archivo enviado exitosamente.");
             }
         });
@@ -240,7 +238,7 @@ public class FileTransferManager {
                 .setData(ByteString.EMPTY).setChunkNumber(chunkNumber).setIsLast(true).build());
             requestObserver.onCompleted();
         } catch (Exception e) {
-            System.out.println();
+            ConsoleOutput.endProgress();
             printMessage("❌ Error leyendo archivo local: " + e.getMessage());
             requestObserver.onError(e);
         }
@@ -265,19 +263,19 @@ public class FileTransferManager {
                     }
                     if (chunk.getIsLast()) success.set(true);
                 } catch (IOException e) {
-                    System.out.println();
+                    ConsoleOutput.endProgress();
                     printMessage("❌ Error escribiendo archivo: " + e.getMessage());
                     throw new RuntimeException(e);
                 }
             }
             @Override public void onError(Throwable t) {
-                System.out.println();
+                ConsoleOutput.endProgress();
                 printMessage("❌ Error recibiendo archivo: " + t.getMessage());
                 closeFile();
             }
             @Override public void onCompleted() {
                 closeFile();
-                System.out.println();
+                ConsoleOutput.endProgress();
                 if (success.get()) printMessage("✅ Archivo recibido y guardado en: " + savePath);
                 else printMessage("⚠️ Transferencia finalizada pero sin confirmación de éxito total.");
             }
@@ -0,0 +1,64 @@
+package com.conference.client;
+
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+
+/**
+ * Per-sender loudness matching for received audio (16-bit signed little-endian PCM).
+ * Each sender's level is tracked with a moving average and a bounded gain, smoothed
+ * over time to avoid pumping, pulls it toward a common target level.
+ */
+public class GainNormalizer {
+
+    static final double TARGET_RMS = 3000.0;
+    static final double MIN_GAIN = 0.5;
+    static final double MAX_GAIN = 4.0;
+    private static final double SILENCE_RMS = 100.0;   // Quieter chunks don't update the estimate
+    private static final double LEVEL_SMOOTHING = 0.1; // Weight of the newest chunk in the level estimate
+    private static final double GAIN_SMOOTHING = 0.05; // Fraction of the gain correction applied per chunk
+
+    private static class SenderLevel {
+        double rms = TARGET_RMS;
+        double gain = 1.0;
+    }
+
+    private final Map<String, SenderLevel> levels = new ConcurrentHashMap<>();
+
+    public byte[] process(String sender, byte[] pcm) {
+        SenderLevel level = levels.computeIfAbsent(sender, k -> new SenderLevel());
+        double chunkRms = rms(pcm);
+        if (chunkRms > SILENCE_RMS) {
+            level.rms = level.rms * (1 - LEVEL_SMOOTHING) + chunkRms * LEVEL_SMOOTHING;
+            double desired = Math.max(MIN_GAIN, Math.min(MAX_GAIN, TARGET_RMS / level.rms));
+            level.gain += (desired - level.gain) * GAIN_SMOOTHING;
+        }
+        return applyGain(pcm, level.gain);
+    }
+
+    public void forget(String sender) {
+        levels.remove(sender);
+    }
+
+    static double rms(byte[] pcm) {
+        int samples = pcm.length / 2;
+        if (samples == 0) return 0;
+        double sum = 0;
+        for (int i = 0; i < samples; i++) {
+            short sample = (short) ((pcm[2 * i] & 0xFF) | (pcm[2 * i + 1] << 8));
+            sum += (double) sample * sample;
+        }
+        return Math.sqrt(sum / samples);
+    }
+
+    static byte[] applyGain(byte[] pcm, double gain) {
+        byte[] out = new byte[pcm.length];
+        for (int i = 0; i + 1 < pcm.length; i += 2) {
+            short sample = (short) ((pcm[i] & 0xFF) | (pcm[i + 1] << 8));
+            int scaled = (int) Math.round(sample * gain);
+            scaled = Math.max(Short.MIN_VALUE, Math.min(Short.MAX_VALUE, scaled));
+            out[i] = (byte) scaled;
+            out[i + 1] = (byte) (scaled >> 8);
+        }
+        return out;
+    }
+}
@@ -0,0 +1,569 @@
+package com.conference.client;
+
+import java.util.Locale;
+import java.util.Map;
+
+/**
+ * Catalog of user-facing strings. Spanish is the default language; a key missing from
+ * the selected catalog falls back to English, and a key missing from both is printed
+ * as-is. Patterns use String.format placeholders.
+ */
+public final class I18n {
+
+    public enum Lang {
+        ES, EN;
+
+        /** Accepts "es", "en" or a locale such as "en_US.UTF-8"; null if not recognized. */
+        public static Lang parse(String value) {
+            if (value == null) return null;
+            String lower = value.toLowerCase(Locale.ROOT);
+            if (lower.startsWith("es")) return ES;
+            if (lower.startsWith("en")) return EN;
+            return null;
+        }
+
+        /** Language from the LANG environment value, Spanish unless it names English. */
+        public static Lang fromEnvironment(String langEnv) {
+            return parse(langEnv) == EN ? EN : ES;
+        }
+    }
+
+    private static final Map<String, String> ES = Map.ofEntries(
+            Map.entry("usage", "Uso: %s"),
+            Map.entry("prompt.server_host", "Dirección del servidor [localhost]: "),
+            Map.entry("prompt.server_port", "Puerto del servidor [50051]: "),
+            Map.entry("prompt.room", "\n🏠 ID de la sala (o escribe 'quit' para salir): "),
+            Map.entry("prompt.name", "👤 Tu nombre de usuario: "),
+            Map.entry("join.title", "                UNIRSE A UNA SALA"),
+            Map.entry("join.empty_room", "❌ ¡El ID de la sala no puede estar vacíos!"),
+            Map.entry("join.empty_name", "❌ ¡El nombre de usuario no puede estar vacíos!"),
+            Map.entry("join.timeout", "⏱️ El servidor no respondió en %d s. Intenta de nuevo más tarde."),
+            Map.entry("join.connected", "Conectado exitosamente como '%s' en sala '%s'"),
+            Map.entry("join.hint", "Ya puedes chatear. Escribe /help para ver todos los comandos."),
+            Map.entry("join.read_only", "👀 Modo solo lectura: no se envían mensajes (usa /quit para salir)."),
+            Map.entry("wait.budget_exhausted", "⌛ Se agotó el tiempo de espera de %d s (--reconnect-budget)."),
+            Map.entry("wait.room_full", "⏳ Sala llena, reintentando en %d s..."),
+            Map.entry("wait.server_unavailable", "⏳ Servidor no disponible, reintentando en %d s..."),
+            Map.entry("room.full", "🚪 La sala está llena, intenta más tarde."),
+            Map.entry("room.no_audio", "🔇 Esta sala no permite audio."),
+            Map.entry("room.audio_policy_pending", "🔇 Aún no se sabe si esta sala permite audio; inténtalo de nuevo en un momento."),
+            Map.entry("audio.no_devices", "🔇 El audio está desactivado: no hay micrófono ni altavoces."),
+            Map.entry("device.none", "No hay ningún micrófono ni altavoz que coincida con '%s'."),
+            Map.entry("device.mic", "🎤 Micrófono seleccionado: %s"),
+            Map.entry("device.speaker", "🔊 Altavoces seleccionados: %s"),
+            Map.entry("device.mic_candidates", "🎤 Varios micrófonos coinciden con '%s': %s. Usa un texto más específico."),
+            Map.entry("device.speaker_candidates", "🔊 Varios altavoces coinciden con '%s': %s. Usa un texto más específico."),
+            Map.entry("device.next_time", "El cambio se aplica la próxima vez que actives el audio (/mic off y /mic on)."),
+            Map.entry("audio.no_devices_notice", "🔇 No se encontró ningún micrófono ni altavoz; el audio queda desactivado y el chat de texto funciona con normalidad."),
+            Map.entry("server.error", "Error del Servidor: %s"),
+            Map.entry("connection.error", "Error en la conexión: %s"),
+            Map.entry("connection.too_large", "Se recibió un mensaje que supera el límite de %d bytes (ver --max-recv-size)."),
+            Map.entry("connection.disconnected", "🔌 Desconectado de la sala."),
+            Map.entry("connection.reconnecting", "🔄 Reconectando a la sala '%s'..."),
+            Map.entry("connection.reconnecting_audio", "🔄 Audio interrumpido, reconectando a la sala '%s'..."),
+            Map.entry("audio.interrupted", "🔇 Audio interrumpido: se perdió la conexión."),
+            Map.entry("share.announce", "%s está compartiendo '%s' (%s)."),
+            Map.entry("share.hint", "   Para descargar, usa: /download %s <ruta_destino>"),
+            Map.entry("command.unknown", "Comando no reconocido: %s"),
+            Map.entry("command.read_only", "👀 Modo solo lectura: %s no está disponible."),
+            Map.entry("command.quit", "Cerrando aplicación..."),
+            Map.entry("command.leave", "Saliendo de la sala..."),
+            Map.entry("message.empty", "Mensaje vacío, no se envió nada."),
+            Map.entry("self.label", "Tú"),
+            Map.entry("display.anonymous", "(anónimo)"),
+            Map.entry("message.empty_content", "(mensaje vacío)"),
+            Map.entry("message.ambiguous_list", "Referencia ambigua '%s'. Coincidencias:"),
+            Map.entry("quote.hint", "   Escribe tu respuesta: se enviará debajo de la cita (/quote cancel para descartarla)."),
+            Map.entry("quote.cancelled", "Cita descartada."),
+            Map.entry("stats.reset", "📊 Estadísticas reiniciadas."),
+            Map.entry("invite.link", "🔗 Comparte este enlace con %s: %s"),
+            Map.entry("invite.open", "   Se abre con: ./run.sh \"%s\""),
+            Map.entry("bookmark.saved", "🔖 Mensaje guardado en %s."),
+            Map.entry("bookmark.save_error", "❌ Error al guardar el marcador: %s"),
+            Map.entry("bookmark.none", "No hay mensajes guardados."),
+            Map.entry("bookmark.title", "── Mensajes guardados ──"),
+            Map.entry("bookmark.read_error", "❌ Error al leer los marcadores: %s"),
+            Map.entry("export.done", "📄 %d mensaje(s) exportados a %s."),
+            Map.entry("export.error", "❌ Error al exportar: %s"),
+            Map.entry("mentions.none", "No hay menciones a @%s en esta sesión."),
+            Map.entry("mentions.title", "── Menciones a @%s ──"),
+            Map.entry("roster.read_error", "⚠️ No se pudo leer %s: %s"),
+            Map.entry("roster.save_error", "⚠️ No se pudo guardar %s: %s"),
+            Map.entry("roominfo.not_found", "La sala '%s' ya no existe en el servidor."),
+            Map.entry("roominfo.unsupported", "El servidor no soporta /roominfo."),
+            Map.entry("roominfo.error", "Error al obtener información de la sala: %s"),
+            Map.entry("echo.result", "🏓 Echo \"%s\" en %d ms (hora del servidor %s)"),
+            Map.entry("echo.unsupported", "El servidor no soporta /echo."),
+            Map.entry("echo.error", "Error en /echo: %s"),
+            Map.entry("audio.monitor_gain_range", "La ganancia debe estar entre 0 y %s."),
+            Map.entry("replay.read_error", "❌ No se pudo leer %s: %s"),
+            Map.entry("replay.invalid_line", "❌ Línea %d inválida: %s"),
+            Map.entry("replay.connected", "🔗 Conectado como '%s' en sala '%s'"),
+            Map.entry("replay.disconnected", "🔌 Desconectado de la sala '%s'"),
+            Map.entry("replay.mic_on", "🎤 Micrófono activado"),
+            Map.entry("replay.mic_off", "🎤 Micrófono desactivado"),
+            Map.entry("message.not_found", "No se encontró el mensaje '%s'."),
+            Map.entry("message.ambiguous", "Referencia ambigua '%s', usa un prefijo más largo."),
+            Map.entry("multiline.start", "📝 Modo multilínea: termina con una línea que contenga solo \".\" o con una línea vacía."),
+            Map.entry("scroll.header", "── Historial: mensajes %d-%d de %d ──"),
+            Map.entry("scroll.more", "── %d mensaje(s) más reciente(s): /scroll down o /scroll bottom ──"),
+            Map.entry("scroll.empty", "No hay mensajes en el historial."),
+            Map.entry("silence.on", "🔕 Ya no escucharás el audio de %s (sus mensajes se siguen mostrando)."),
+            Map.entry("silence.off", "🔔 Vuelves a escuchar el audio de %s."),
+            Map.entry("silence.not_silenced", "%s no estaba silenciado."),
+            Map.entry("silence.none", "No hay usuarios silenciados."),
+            Map.entry("silence.list", "🔕 Audio silenciado de: %s"),
+            Map.entry("nudge.received", "👋 %s te envió un toque"),
+            Map.entry("nudge.sent", "👋 Toque enviado a %s."),
+            Map.entry("nudge.self", "No puedes enviarte un toque a ti mismo."),
+            Map.entry("nudge.failed", "No se pudo enviar el toque: '%s' no está en la sala."),
+            Map.entry("history.joined", "%s entró a la sala"),
+            Map.entry("history.left", "%s salió de la sala"),
+            Map.entry("audio.transmitting", "🎤 Micrófono transmitiendo a la sala."),
+            Map.entry("audio.already_active", "El audio ya está activo."),
+            Map.entry("audio.monitor_on", "🎧 Monitor activado (ganancia %s). Usa audífonos: con altavoces se producirá acople."),
+            Map.entry("audio.monitor_off", "🎧 Monitor desactivado."),
+            Map.entry("audio.mono_fallback", "⚠️ El dispositivo no soporta audio estéreo, se usará mono."),
+            Map.entry("audio.devices", "🎧 Micrófono: %s | Altavoces: %s"),
+            Map.entry("audio.on", "🎤 Micrófono y altavoces activados."),
+            Map.entry("audio.monitor_only", "🎤 Micrófono abierto solo para el monitor local."),
+            Map.entry("audio.off", "🎤 Micrófono y altavoces desactivados."),
+            Map.entry("audio.listening", "🔈 Altavoces activados, micrófono apagado."),
+            Map.entry("audio_only.banner", "📻 Modo solo audio en la sala '%s': no se muestra el chat. Escribe /quit para salir."),
+            Map.entry("audio_only.restricted", "📻 Modo solo audio: solo están disponibles /quit, /exit y /leave."),
+            Map.entry("audio.send_error", "Error al enviar audio: %s"),
+            Map.entry("audio.device_error", "Error al acceder a dispositivo de audio: %s"),
+            Map.entry("calibration.silence", "🎚️ Calibrando el micrófono: guarda silencio un momento..."),
+            Map.entry("calibration.speak", "🎚️ Di algo para calibrar..."),
+            Map.entry("calibration.no_speech", "🎚️ No se distinguió la voz del ruido de fondo; se mantiene la ganancia anterior (repite con /mic calibrate)."),
+            Map.entry("calibration.done", "🎚️ Ganancia del micrófono: %.2f, umbral de silencio: %.0f (guardados en %s)."),
+            Map.entry("calibration.save_error", "⚠️ No se pudo guardar %s: %s"),
+            Map.entry("calibration.mic_busy", "Apaga el micrófono y el monitor antes de calibrar (/mic off, /monitor off)."),
+            Map.entry("tone.busy", "Ya se está enviando un tono."),
+            Map.entry("tone.sending", "🔊 Enviando tono de %d Hz durante %.1f s..."),
+            Map.entry("tone.error", "Error al enviar tono: %s"),
+            Map.entry("app.interrupted", "Chat interrumpido: %s"),
+            Map.entry("app.closing", "Cerrando conexión..."),
+            Map.entry("control.token", "🔑 Puerto de control 127.0.0.1:%d, envía este token como primera línea: %s"),
+            Map.entry("banner.unresolved", "dirección no resuelta"),
+            Map.entry("banner.line", "🔗 Servidor %s → %s, HTTP/2, TLS %s"),
+            Map.entry("tls.on", "activo"),
+            Map.entry("tls.off", "inactivo"),
+            Map.entry("whoami.audio_blocked", "no permitido en esta sala"),
+            Map.entry("whoami.mic_on", "micrófono activo"),
+            Map.entry("whoami.mic_off", "micrófono inactivo"),
+            Map.entry("whoami.speakers_on", ", altavoces activos"),
+            Map.entry("whoami.speakers_off", ", altavoces inactivos"),
+            Map.entry("whoami.monitor", ", monitor activo"),
+            Map.entry("whoami.body", "🪪 Sesión actual:\n   Nombre:   %s\n   Sala:     %s\n   Servidor: %s (TLS %s)\n   Trazas:   %s-…\n   Audio:    %s"),
+            Map.entry("reply.unknown", "  ↳ (respuesta a mensaje anterior)"),
+            Map.entry("reply.context", "  ↳ en respuesta a %s: %s"),
+            Map.entry("roster.last", "👥 Vistos la última vez en esta sala: %s"),
+            Map.entry("roominfo.title", "ℹ️  Información de la sala"),
+            Map.entry("roominfo.name", "Nombre:"),
+            Map.entry("roominfo.members", "Miembros:"),
+            Map.entry("roominfo.created", "Creada:"),
+            Map.entry("roominfo.password", "Contraseña:"),
+            Map.entry("roominfo.audio", "Audio:"),
+            Map.entry("roominfo.yes", "sí"),
+            Map.entry("roominfo.no", "no"),
+            Map.entry("roominfo.audio_allowed", "permitido"),
+            Map.entry("roominfo.audio_blocked", "no permitido"),
+            Map.entry("p2p.request", "\nSolicitud de archivo 1-a-1 recibida:"),
+            Map.entry("p2p.request_from", "  De: %s"),
+            Map.entry("p2p.request_file", "  Archivo: %s (%d bytes)"),
+            Map.entry("p2p.request_accept", "  Para aceptar: /accept %s <ruta_destino>"),
+            Map.entry("p2p.request_reject", "  Para rechazar: /reject %s"),
+            Map.entry("p2p.invalid_size", "Error: Formato de tamaño de archivo inválido en la notificación."),
+            Map.entry("app.error", "❌ %s"),
+            Map.entry("control.open_error", "❌ No se pudo abrir el puerto de control %d: %s"),
+            Map.entry("record.open_error", "❌ No se pudo crear %s: %s"),
+            Map.entry("stats.title", "📊 Estadísticas de la sesión"),
+            Map.entry("stats.sent", "Mensajes enviados:"),
+            Map.entry("stats.received", "Mensajes recibidos:"),
+            Map.entry("stats.duplicates", "Duplicados descartados:"),
+            Map.entry("stats.audio_sent", "Audio enviado:"),
+            Map.entry("stats.audio_received", "Audio recibido:"),
+            Map.entry("stats.audio_rejected", "Audio rechazado:"),
+            Map.entry("summary.title", "📋 Resumen de la sesión"),
+            Map.entry("summary.connected", "Tiempo conectado:"),
+            Map.entry("summary.messages", "Mensajes env./rec.:"),
+            Map.entry("summary.audio", "Audio env./rec.:"),
+            Map.entry("summary.reconnects", "Reconexiones:"),
+            Map.entry("summary.latency", "Latencia:"),
+            Map.entry("summary.latency_none", "sin mediciones (/echo)"),
+            Map.entry("summary.latency_value", "prom. %d ms, máx. %d ms"),
+            Map.entry("caps.title", "🔎 Diagnóstico de audio"),
+            Map.entry("caps.mic", "Micrófono"),
+            Map.entry("caps.speakers", "Altavoces"),
+            Map.entry("caps.no_formats", "  (sin formatos disponibles)"),
+            Map.entry("caps.encoding", "Codificación"),
+            Map.entry("caps.bits", "Bits"),
+            Map.entry("caps.channels", "Canales"),
+            Map.entry("caps.rate", "Frecuencia"),
+            Map.entry("caps.order", "Orden"),
+            Map.entry("caps.any_rate", "cualquiera"),
+            Map.entry("caps.any_channels", "cualq."),
+            Map.entry("caps.client_format", "  Formato del cliente (%.0f Hz, %d bits, %d canal(es)): %s"),
+            Map.entry("caps.supported", "soportado sin conversión"),
+            Map.entry("caps.unsupported", "NO soportado, el dispositivo requiere conversión"),
+            Map.entry("device.system_default", "predeterminado del sistema"),
+            Map.entry("device.no_format", "ningún dispositivo acepta %s"),
+            Map.entry("check.start", "Comprobando conexión con %s..."),
+            Map.entry("check.timeout", "❌ Sin respuesta del servidor tras %d s."),
+            Map.entry("check.join_failed", "❌ Falló la unión: %s"),
+            Map.entry("check.closed", "el servidor cerró el stream antes de la bienvenida"),
+            Map.entry("check.joined", "✅ Unión aceptada en %d ms."),
+            Map.entry("check.round_trip", "✅ Ida y vuelta de consulta en %d ms."),
+            Map.entry("check.error", "❌ Error durante la comprobación: %s"),
+            Map.entry("options.usage", "Uso: java -jar chat-client.jar [check] [opciones] [nombre [sala] | elo330://host:puerto/sala?name=...]"),
+            Map.entry("options.usage_replay", "     java -jar chat-client.jar replay <archivo.jsonl> [--replay-speed <n>]"),
+            Map.entry("options.positional", "  nombre, sala                Nombre de usuario y sala para la primera unión, sin preguntarlos"),
+            Map.entry("options.invite", "  elo330://...                Enlace de /invite: servidor, sala y nombre sugerido"),
+            Map.entry("options.room_from_hostname", "  --room-from-hostname        Sin sala indicada, usar el nombre de la máquina como sala (también: variable CHAT_ROOM)"),
+            Map.entry("options.check", "  check                       Comprobar la conexión con el servidor y salir (código 0 si funciona)"),
+            Map.entry("options.replay", "  replay <archivo.jsonl>      Reproducir una sesión grabada con --record-session y salir"),
+            Map.entry("options.replay_speed", "  --replay-speed <n>          Velocidad de replay: 1 = tiempo original, n = n veces más rápido, 0 = sin pausas"),
+            Map.entry("options.record_session", "  --record-session <archivo>  Grabar los eventos de la sesión (mensajes, comandos, conexión, audio) en JSON lines"),
+            Map.entry("options.server", "  --server <host[:puerto]>    Servidor a usar sin preguntarlo (puerto por defecto %d)"),
+            Map.entry("options.join_timeout", "  --join-timeout <segundos>   Tiempo máximo de espera al unirse a una sala (por defecto %d)"),
+            Map.entry("options.max_recv_size", "  --max-recv-size <bytes>     Tamaño máximo de un mensaje recibido (por defecto %d)"),
+            Map.entry("options.dedupe_window", "  --dedupe-window <n>         Mensajes recientes recordados para descartar duplicados, 0 desactiva (por defecto %d)"),
+            Map.entry("options.wait_for_server", "  --wait-for-server           Si el servidor no responde en la primera unión, reintentar hasta que esté disponible"),
+            Map.entry("options.wait_for_room", "  --wait-for-room             Si la sala está llena, reintentar hasta que haya lugar (sin esta opción termina con código 3)"),
+            Map.entry("options.reconnect_budget", "  --reconnect-budget <seg>    Tiempo total máximo de reintentos de --wait-for-server/--wait-for-room, 0 sin límite (por defecto 0)"),
+            Map.entry("options.read_only", "  --read-only                 Solo mirar la sala: no se envían mensajes, audio ni archivos"),
+            Map.entry("options.roster_cache", "  --roster-cache              Recordar en roster.json quién estuvo en cada sala y mostrarlo al volver"),
+            Map.entry("options.no_summary", "  --no-summary                No mostrar el resumen de la sesión al salir"),
+            Map.entry("options.alias", "  --alias </atajo=/comando>   Definir un atajo de comando, p. ej. --alias \"/mute=/mic off\" (repetible)"),
+            Map.entry("options.plain", "  --plain                     Salida línea a línea sin prompt ni secuencias ANSI (automático si stdout no es una terminal)"),
+            Map.entry("options.scrollback", "  --scrollback <n>            Mensajes de la sala que se guardan en memoria (por defecto %d)"),
+            Map.entry("options.max_audio_chunk_bytes", "  --max-audio-chunk-bytes <n> Tamaño máximo de un fragmento de audio recibido; los mayores se descartan (por defecto %d)"),
+            Map.entry("options.control_port", "  --control-port <puerto>     Aceptar comandos (say, mic, join, quit) de otros programas en 127.0.0.1:<puerto>; cada conexión debe enviar primero el token que se muestra al iniciar"),
+            Map.entry("options.audio_only", "  --audio-only                Solo escuchar el audio de la sala, sin chat ni prompt (solo /quit y /leave)"),
+            Map.entry("options.lang", "  --lang <es|en>              Idioma de los mensajes (por defecto según LANG, si no español)"),
+            Map.entry("options.no_redraw", "  --no-redraw                 Añadir los mensajes sin redibujar el prompt ni usar secuencias ANSI (automático con TERM=dumb)"),
+            Map.entry("options.render_coalesce", "  --render-coalesce-ms <ms>   Agrupar los mensajes que llegan dentro de esa ventana en un solo redibujado, 0 desactiva (por defecto 0)"),
+            Map.entry("options.audio_channels", "  --audio-channels <1|2>      Canales de captura y reproducción (por defecto 1, mono)"),
+            Map.entry("options.monitor_delay", "  --monitor-delay-ms <ms>     Retardo del monitor local de /monitor, 0 a 1000 (por defecto %d)"),
+            Map.entry("options.alert", "  --alert                     Aviso sonoro al ser mencionado o recibir un mensaje privado"),
+            Map.entry("options.mention_sound", "  --mention-sound <ruta.wav>  Sonido del aviso en lugar de la campana de la terminal"),
+            Map.entry("options.bell_on_message", "  --bell-on-message <modo>    Campana de la terminal al recibir mensajes: off, mention o all (por defecto off)"),
+            Map.entry("options.stats_interval", "  --stats-interval <segundos> Registrar las estadísticas en stderr periódicamente, 0 desactiva (por defecto 0)"),
+            Map.entry("options.echo_self", "  --echo-self                 Mostrar tus propios mensajes en la vista, marcados como \"Tú\""),
+            Map.entry("options.mic_priority", "  --mic-priority <a,b,...>    Micrófonos preferidos por nombre, en orden; si ninguno está, el predeterminado"),
+            Map.entry("options.speaker_priority", "  --speaker-priority <a,b,..> Altavoces preferidos por nombre, en orden"),
+            Map.entry("options.markdown", "  --markdown                  Mostrar *negrita*, _cursiva_ y `código` en los mensajes recibidos"),
+            Map.entry("options.verbose", "  --verbose                   Escribir en stderr el trace_id y timestamp de cada mensaje enviado"),
+            Map.entry("options.quiet", "  --quiet                     No mostrar la línea de diagnóstico de conexión al unirse"),
+            Map.entry("options.check_first", "'check' debe ser el primer argumento"),
+            Map.entry("options.replay_first", "'replay' debe ser el primer argumento"),
+            Map.entry("options.audio_channels_invalid", "--audio-channels debe ser 1 o 2"),
+            Map.entry("options.monitor_delay_invalid", "--monitor-delay-ms no puede superar 1000"),
+            Map.entry("options.bell_invalid", "--bell-on-message debe ser off, mention o all"),
+            Map.entry("options.lang_invalid", "--lang debe ser es o en"),
+            Map.entry("options.control_port_invalid", "--control-port debe estar entre 1 y 65535"),
+            Map.entry("options.coalesce_invalid", "--render-coalesce-ms no puede superar 1000"),
+            Map.entry("options.unknown", "Opción desconocida: %s"),
+            Map.entry("options.hostname_room", "No se pudo obtener una sala válida del nombre de la máquina: %s"),
+            Map.entry("options.env_room", "CHAT_ROOM no es un ID de sala válido: %s"),
+            Map.entry("options.empty_name", "El nombre de usuario no puede estar vacío"),
+            Map.entry("options.empty_room", "El ID de la sala no puede estar vacío"),
+            Map.entry("options.unexpected", "Argumento inesperado: %s"),
+            Map.entry("options.room_twice", "Sala indicada dos veces: %s"),
+            Map.entry("options.server_bracket", "Falta ']' en --server: %s"),
+            Map.entry("options.server_port_after_bracket", "Se esperaba ':puerto' tras ']' en --server: %s"),
+            Map.entry("options.server_ipv6", "Las direcciones IPv6 van entre corchetes en --server, p. ej. [::1]:%d: %s"),
+            Map.entry("options.server_host", "Falta el host en --server: %s"),
+            Map.entry("options.server_port", "Puerto inválido en --server: %s"),
+            Map.entry("options.server_port_range", "El puerto de --server debe estar entre 1 y 65535: %s"),
+            Map.entry("options.missing_value", "Falta el valor para %s"),
+            Map.entry("options.invalid_value", "Valor inválido para %s: %s"),
+            Map.entry("app.goodbye", "¡Adiós!"));
+
+    private static final Map<String, String> EN = Map.ofEntries(
+            Map.entry("usage", "Usage: %s"),
+            Map.entry("prompt.server_host", "Server address [localhost]: "),
+            Map.entry("prompt.server_port", "Server port [50051]: "),
+            Map.entry("prompt.room", "\n🏠 Room ID (or type 'quit' to exit): "),
+            Map.entry("prompt.name", "👤 Your user name: "),
+            Map.entry("join.title", "                  JOIN A ROOM"),
+            Map.entry("join.empty_room", "❌ The room ID cannot be empty!"),
+            Map.entry("join.empty_name", "❌ The user name cannot be empty!"),
+            Map.entry("join.timeout", "⏱️ The server did not answer within %d s. Try again later."),
+            Map.entry("join.connected", "Connected as '%s' in room '%s'"),
+            Map.entry("join.hint", "You can start chatting. Type /help to see every command."),
+            Map.entry("join.read_only", "👀 Read-only mode: messages are not sent (use /quit to exit)."),
+            Map.entry("wait.budget_exhausted", "⌛ Gave up waiting after %d s (--reconnect-budget)."),
+            Map.entry("wait.room_full", "⏳ Room full, retrying in %d s..."),
+            Map.entry("wait.server_unavailable", "⏳ Server unavailable, retrying in %d s..."),
+            Map.entry("room.full", "🚪 The room is full, try again later."),
+            Map.entry("room.no_audio", "🔇 This room does not allow audio."),
+            Map.entry("room.audio_policy_pending", "🔇 It is not known yet whether this room allows audio; try again in a moment."),
+            Map.entry("audio.no_devices", "🔇 Audio is disabled: there is no microphone or speaker."),
+            Map.entry("device.none", "No microphone or speaker matches '%s'."),
+            Map.entry("device.mic", "🎤 Microphone selected: %s"),
+            Map.entry("device.speaker", "🔊 Speakers selected: %s"),
+            Map.entry("device.mic_candidates", "🎤 Several microphones match '%s': %s. Use a more specific text."),
+            Map.entry("device.speaker_candidates", "🔊 Several speakers match '%s': %s. Use a more specific text."),
+            Map.entry("device.next_time", "The change applies the next time you turn audio on (/mic off and /mic on)."),
+            Map.entry("audio.no_devices_notice", "🔇 No microphone or speaker was found; audio is disabled and text chat works as usual."),
+            Map.entry("server.error", "Server error: %s"),
+            Map.entry("connection.error", "Connection error: %s"),
+            Map.entry("connection.too_large", "Received a message larger than the %d byte limit (see --max-recv-size)."),
+            Map.entry("connection.disconnected", "🔌 Disconnected from the room."),
+            Map.entry("connection.reconnecting", "🔄 Reconnecting to room '%s'..."),
+            Map.entry("connection.reconnecting_audio", "🔄 Audio interrupted, reconnecting to room '%s'..."),
+            Map.entry("audio.interrupted", "🔇 Audio interrupted: the connection was lost."),
+            Map.entry("share.announce", "%s is sharing '%s' (%s)."),
+            Map.entry("share.hint", "   To download it, use: /download %s <destination_path>"),
+            Map.entry("command.unknown", "Unknown command: %s"),
+            Map.entry("command.read_only", "👀 Read-only mode: %s is not available."),
+            Map.entry("command.quit", "Closing the application..."),
+            Map.entry("command.leave", "Leaving the room..."),
+            Map.entry("message.empty", "Empty message, nothing was sent."),
+            Map.entry("self.label", "You"),
+            Map.entry("display.anonymous", "(anonymous)"),
+            Map.entry("message.empty_content", "(empty message)"),
+            Map.entry("message.ambiguous_list", "Ambiguous reference '%s'. Matches:"),
+            Map.entry("quote.hint", "   Type your reply: it will be sent below the quote (/quote cancel to drop it)."),
+            Map.entry("quote.cancelled", "Quote dropped."),
+            Map.entry("stats.reset", "📊 Statistics reset."),
+            Map.entry("invite.link", "🔗 Share this link with %s: %s"),
+            Map.entry("invite.open", "   Open it with: ./run.sh \"%s\""),
+            Map.entry("bookmark.saved", "🔖 Message saved to %s."),
+            Map.entry("bookmark.save_error", "❌ Error saving the bookmark: %s"),
+            Map.entry("bookmark.none", "There are no saved messages."),
+            Map.entry("bookmark.title", "── Saved messages ──"),
+            Map.entry("bookmark.read_error", "❌ Error reading the bookmarks: %s"),
+            Map.entry("export.done", "📄 %d message(s) exported to %s."),
+            Map.entry("export.error", "❌ Export failed: %s"),
+            Map.entry("mentions.none", "No mentions of @%s in this session."),
+            Map.entry("mentions.title", "── Mentions of @%s ──"),
+            Map.entry("roster.read_error", "⚠️ Could not read %s: %s"),
+            Map.entry("roster.save_error", "⚠️ Could not save %s: %s"),
+            Map.entry("roominfo.not_found", "Room '%s' no longer exists on the server."),
+            Map.entry("roominfo.unsupported", "The server does not support /roominfo."),
+            Map.entry("roominfo.error", "Error getting the room information: %s"),
+            Map.entry("echo.result", "🏓 Echo \"%s\" in %d ms (server time %s)"),
+            Map.entry("echo.unsupported", "The server does not support /echo."),
+            Map.entry("echo.error", "/echo failed: %s"),
+            Map.entry("audio.monitor_gain_range", "The gain must be between 0 and %s."),
+            Map.entry("replay.read_error", "❌ Could not read %s: %s"),
+            Map.entry("replay.invalid_line", "❌ Invalid line %d: %s"),
+            Map.entry("replay.connected", "🔗 Connected as '%s' in room '%s'"),
+            Map.entry("replay.disconnected", "🔌 Disconnected from room '%s'"),
+            Map.entry("replay.mic_on", "🎤 Microphone on"),
+            Map.entry("replay.mic_off", "🎤 Microphone off"),
+            Map.entry("message.not_found", "Message '%s' not found."),
+            Map.entry("message.ambiguous", "Ambiguous reference '%s', use a longer prefix."),
+            Map.entry("multiline.start", "📝 Multi-line mode: finish with a line containing only \".\" or with an empty line."),
+            Map.entry("scroll.header", "── History: messages %d-%d of %d ──"),
+            Map.entry("scroll.more", "── %d newer message(s): /scroll down or /scroll bottom ──"),
+            Map.entry("scroll.empty", "There are no messages in the history."),
+            Map.entry("silence.on", "🔕 You will no longer hear %s's audio (their messages are still shown)."),
+            Map.entry("silence.off", "🔔 You hear %s's audio again."),
+            Map.entry("silence.not_silenced", "%s was not silenced."),
+            Map.entry("silence.none", "No users are silenced."),
+            Map.entry("silence.list", "🔕 Audio silenced from: %s"),
+            Map.entry("nudge.received", "👋 %s nudged you"),
+            Map.entry("nudge.sent", "👋 Nudge sent to %s."),
+            Map.entry("nudge.self", "You cannot nudge yourself."),
+            Map.entry("nudge.failed", "The nudge was not sent: '%s' is not in the room."),
+            Map.entry("history.joined", "%s joined the room"),
+            Map.entry("history.left", "%s left the room"),
+            Map.entry("audio.transmitting", "🎤 Microphone transmitting to the room."),
+            Map.entry("audio.already_active", "Audio is already active."),
+            Map.entry("audio.monitor_on", "🎧 Monitor on (gain %s). Use headphones: speakers will cause feedback."),
+            Map.entry("audio.monitor_off", "🎧 Monitor off."),
+            Map.entry("audio.mono_fallback", "⚠️ The device does not support stereo audio, using mono."),
+            Map.entry("audio.devices", "🎧 Microphone: %s | Speakers: %s"),
+            Map.entry("audio.on", "🎤 Microphone and speakers on."),
+            Map.entry("audio.monitor_only", "🎤 Microphone open for the local monitor only."),
+            Map.entry("audio.off", "🎤 Microphone and speakers off."),
+            Map.entry("audio.listening", "🔈 Speakers on, microphone off."),
+            Map.entry("audio_only.banner", "📻 Audio-only mode in room '%s': chat is not shown. Type /quit to exit."),
+            Map.entry("audio_only.restricted", "📻 Audio-only mode: only /quit, /exit and /leave are available."),
+            Map.entry("audio.send_error", "Error sending audio: %s"),
+            Map.entry("audio.device_error", "Error opening the audio device: %s"),
+            Map.entry("calibration.silence", "🎚️ Calibrating the microphone: stay quiet for a moment..."),
+            Map.entry("calibration.speak", "🎚️ Say something to calibrate..."),
+            Map.entry("calibration.no_speech", "🎚️ Could not tell your voice from the background noise; keeping the previous gain (retry with /mic calibrate)."),
+            Map.entry("calibration.done", "🎚️ Microphone gain: %.2f, silence threshold: %.0f (saved to %s)."),
+            Map.entry("calibration.save_error", "⚠️ Could not save %s: %s"),
+            Map.entry("calibration.mic_busy", "Turn off the microphone and the monitor before calibrating (/mic off, /monitor off)."),
+            Map.entry("tone.busy", "A tone is already being sent."),
+            Map.entry("tone.sending", "🔊 Sending a %d Hz tone for %.1f s..."),
+            Map.entry("tone.error", "Error sending tone: %s"),
+            Map.entry("app.interrupted", "Chat interrupted: %s"),
+            Map.entry("app.closing", "Closing connection..."),
+            Map.entry("control.token", "🔑 Control port 127.0.0.1:%d, send this token as the first line: %s"),
+            Map.entry("banner.unresolved", "address not resolved"),
+            Map.entry("banner.line", "🔗 Server %s → %s, HTTP/2, TLS %s"),
+            Map.entry("tls.on", "on"),
+            Map.entry("tls.off", "off"),
+            Map.entry("whoami.audio_blocked", "not allowed in this room"),
+            Map.entry("whoami.mic_on", "microphone on"),
+            Map.entry("whoami.mic_off", "microphone off"),
+            Map.entry("whoami.speakers_on", ", speakers on"),
+            Map.entry("whoami.speakers_off", ", speakers off"),
+            Map.entry("whoami.monitor", ", monitor on"),
+            Map.entry("whoami.body", "🪪 Current session:\n   Name:     %s\n   Room:     %s\n   Server:   %s (TLS %s)\n   Traces:   %s-…\n   Audio:    %s"),
+            Map.entry("reply.unknown", "  ↳ (reply to an earlier message)"),
+            Map.entry("reply.context", "  ↳ in reply to %s: %s"),
+            Map.entry("roster.last", "👥 Last seen in this room: %s"),
+            Map.entry("roominfo.title", "ℹ️  Room information"),
+            Map.entry("roominfo.name", "Name:"),
+            Map.entry("roominfo.members", "Members:"),
+            Map.entry("roominfo.created", "Created:"),
+            Map.entry("roominfo.password", "Password:"),
+            Map.entry("roominfo.audio", "Audio:"),
+            Map.entry("roominfo.yes", "yes"),
+            Map.entry("roominfo.no", "no"),
+            Map.entry("roominfo.audio_allowed", "allowed"),
+            Map.entry("roominfo.audio_blocked", "not allowed"),
+            Map.entry("p2p.request", "\nOne-to-one file request received:"),
+            Map.entry("p2p.request_from", "  From: %s"),
+            Map.entry("p2p.request_file", "  File: %s (%d bytes)"),
+            Map.entry("p2p.request_accept", "  To accept: /accept %s <destination_path>"),
+            Map.entry("p2p.request_reject", "  To reject: /reject %s"),
+            Map.entry("p2p.invalid_size", "Error: Invalid file size in the notification."),
+            Map.entry("app.error", "❌ %s"),
+            Map.entry("control.open_error", "❌ Could not open control port %d: %s"),
+            Map.entry("record.open_error", "❌ Could not create %s: %s"),
+            Map.entry("stats.title", "📊 Session statistics"),
+            Map.entry("stats.sent", "Messages sent:"),
+            Map.entry("stats.received", "Messages received:"),
+            Map.entry("stats.duplicates", "Duplicates dropped:"),
+            Map.entry("stats.audio_sent", "Audio sent:"),
+            Map.entry("stats.audio_received", "Audio received:"),
+            Map.entry("stats.audio_rejected", "Audio rejected:"),
+            Map.entry("summary.title", "📋 Session summary"),
+            Map.entry("summary.connected", "Time connected:"),
+            Map.entry("summary.messages", "Messages sent/recv.:"),
+            Map.entry("summary.audio", "Audio sent/recv.:"),
+            Map.entry("summary.reconnects", "Reconnects:"),
+            Map.entry("summary.latency", "Latency:"),
+            Map.entry("summary.latency_none", "no measurements (/echo)"),
+            Map.entry("summary.latency_value", "avg. %d ms, max. %d ms"),
+            Map.entry("caps.title", "🔎 Audio diagnostics"),
+            Map.entry("caps.mic", "Microphone"),
+            Map.entry("caps.speakers", "Speakers"),
+            Map.entry("caps.no_formats", "  (no formats available)"),
+            Map.entry("caps.encoding", "Encoding"),
+            Map.entry("caps.bits", "Bits"),
+            Map.entry("caps.channels", "Channels"),
+            Map.entry("caps.rate", "Rate"),
+            Map.entry("caps.order", "Order"),
+            Map.entry("caps.any_rate", "any"),
+            Map.entry("caps.any_channels", "any"),
+            Map.entry("caps.client_format", "  Client format (%.0f Hz, %d bits, %d channel(s)): %s"),
+            Map.entry("caps.supported", "supported without conversion"),
+            Map.entry("caps.unsupported", "NOT supported, the device needs conversion"),
+            Map.entry("device.system_default", "system default"),
+            Map.entry("device.no_format", "no device accepts %s"),
+            Map.entry("check.start", "Checking the connection to %s..."),
+            Map.entry("check.timeout", "❌ No answer from the server after %d s."),
+            Map.entry("check.join_failed", "❌ Join failed: %s"),
+            Map.entry("check.closed", "the server closed the stream before the welcome"),
+            Map.entry("check.joined", "✅ Join accepted in %d ms."),
+            Map.entry("check.round_trip", "✅ Query round trip in %d ms."),
+            Map.entry("check.error", "❌ Error during the check: %s"),
+            Map.entry("options.usage", "Usage: java -jar chat-client.jar [check] [options] [name [room] | elo330://host:port/room?name=...]"),
+            Map.entry("options.usage_replay", "       java -jar chat-client.jar replay <file.jsonl> [--replay-speed <n>]"),
+            Map.entry("options.positional", "  name, room                  User name and room for the first join, without asking for them"),
+            Map.entry("options.invite", "  elo330://...                /invite link: server, room and suggested name"),
+            Map.entry("options.room_from_hostname", "  --room-from-hostname        Without a room given, use the machine name as the room (also: CHAT_ROOM variable)"),
+            Map.entry("options.check", "  check                       Check the connection to the server and exit (code 0 if it works)"),
+            Map.entry("options.replay", "  replay <file.jsonl>         Replay a session recorded with --record-session and exit"),
+            Map.entry("options.replay_speed", "  --replay-speed <n>          Replay speed: 1 = original timing, n = n times faster, 0 = no pauses"),
+            Map.entry("options.record_session", "  --record-session <file>     Record the session events (messages, commands, connection, audio) as JSON lines"),
+            Map.entry("options.server", "  --server <host[:port]>      Server to use without asking (default port %d)"),
+            Map.entry("options.join_timeout", "  --join-timeout <seconds>    Maximum wait when joining a room (default %d)"),
+            Map.entry("options.max_recv_size", "  --max-recv-size <bytes>     Maximum size of a received message (default %d)"),
+            Map.entry("options.dedupe_window", "  --dedupe-window <n>         Recent messages remembered to drop duplicates, 0 disables (default %d)"),
+            Map.entry("options.wait_for_server", "  --wait-for-server           If the server does not answer on the first join, retry until it is available"),
+            Map.entry("options.wait_for_room", "  --wait-for-room             If the room is full, retry until there is room (without this option exits with code 3)"),
+            Map.entry("options.reconnect_budget", "  --reconnect-budget <sec>    Maximum total retry time for --wait-for-server/--wait-for-room, 0 unlimited (default 0)"),
+            Map.entry("options.read_only", "  --read-only                 Only watch the room: no messages, audio or files are sent"),
+            Map.entry("options.roster_cache", "  --roster-cache              Remember in roster.json who was in each room and show it on return"),
+            Map.entry("options.no_summary", "  --no-summary                Do not show the session summary on exit"),
+            Map.entry("options.alias", "  --alias </short=/command>   Define a command shortcut, e.g. --alias \"/mute=/mic off\" (repeatable)"),
+            Map.entry("options.plain", "  --plain                     Line-by-line output without prompt or ANSI sequences (automatic if stdout is not a terminal)"),
+            Map.entry("options.scrollback", "  --scrollback <n>            Room messages kept in memory (default %d)"),
+            Map.entry("options.max_audio_chunk_bytes", "  --max-audio-chunk-bytes <n> Maximum size of a received audio chunk; larger ones are dropped (default %d)"),
+            Map.entry("options.control_port", "  --control-port <port>       Accept commands (say, mic, join, quit) from other programs on 127.0.0.1:<port>; each connection must first send the token shown at startup"),
+            Map.entry("options.audio_only", "  --audio-only                Only listen to the room audio, without chat or prompt (only /quit and /leave)"),
+            Map.entry("options.lang", "  --lang <es|en>              Language of the messages (default from LANG, otherwise Spanish)"),
+            Map.entry("options.no_redraw", "  --no-redraw                 Append messages without redrawing the prompt or using ANSI sequences (automatic with TERM=dumb)"),
+            Map.entry("options.render_coalesce", "  --render-coalesce-ms <ms>   Group messages arriving within that window into a single redraw, 0 disables (default 0)"),
+            Map.entry("options.audio_channels", "  --audio-channels <1|2>      Capture and playback channels (default 1, mono)"),
+            Map.entry("options.monitor_delay", "  --monitor-delay-ms <ms>     Delay of the local /monitor, 0 to 1000 (default %d)"),
+            Map.entry("options.alert", "  --alert                     Sound alert when mentioned or sent a private message"),
+            Map.entry("options.mention_sound", "  --mention-sound <path.wav>  Alert sound instead of the terminal bell"),
+            Map.entry("options.bell_on_message", "  --bell-on-message <mode>    Terminal bell on received messages: off, mention or all (default off)"),
+            Map.entry("options.stats_interval", "  --stats-interval <seconds>  Log the statistics to stderr periodically, 0 disables (default 0)"),
+            Map.entry("options.echo_self", "  --echo-self                 Show your own messages in the view, marked as \"You\""),
+            Map.entry("options.mic_priority", "  --mic-priority <a,b,...>    Preferred microphones by name, in order; if none is present, the default"),
+            Map.entry("options.speaker_priority", "  --speaker-priority <a,b,..> Preferred speakers by name, in order"),
+            Map.entry("options.markdown", "  --markdown                  Show *bold*, _italic_ and `code` in received messages"),
+            Map.entry("options.verbose", "  --verbose                   Write the trace_id and timestamp of each sent message to stderr"),
+            Map.entry("options.quiet", "  --quiet                     Do not show the connection diagnostic line when joining"),
+            Map.entry("options.check_first", "'check' must be the first argument"),
+            Map.entry("options.replay_first", "'replay' must be the first argument"),
+            Map.entry("options.audio_channels_invalid", "--audio-channels must be 1 or 2"),
+            Map.entry("options.monitor_delay_invalid", "--monitor-delay-ms cannot exceed 1000"),
+            Map.entry("options.bell_invalid", "--bell-on-message must be off, mention or all"),
+            Map.entry("options.lang_invalid", "--lang must be es or en"),
+            Map.entry("options.control_port_invalid", "--control-port must be between 1 and 65535"),
+            Map.entry("options.coalesce_invalid", "--render-coalesce-ms cannot exceed 1000"),
+            Map.entry("options.unknown", "Unknown option: %s"),
+            Map.entry("options.hostname_room", "Could not derive a valid room from the machine name: %s"),
+            Map.entry("options.env_room", "CHAT_ROOM is not a valid room ID: %s"),
+            Map.entry("options.empty_name", "The user name cannot be empty"),
+            Map.entry("options.empty_room", "The room ID cannot be empty"),
+            Map.entry("options.unexpected", "Unexpected argument: %s"),
+            Map.entry("options.room_twice", "Room given twice: %s"),
+            Map.entry("options.server_bracket", "Missing ']' in --server: %s"),
+            Map.entry("options.server_port_after_bracket", "Expected ':port' after ']' in --server: %s"),
+            Map.entry("options.server_ipv6", "IPv6 addresses go in brackets in --server, e.g. [::1]:%d: %s"),
+            Map.entry("options.server_host", "Missing host in --server: %s"),
+            Map.entry("options.server_port", "Invalid port in --server: %s"),
+            Map.entry("options.server_port_range", "The --server port must be between 1 and 65535: %s"),
+            Map.entry("options.missing_value", "Missing value for %s"),
+            Map.entry("options.invalid_value", "Invalid value for %s: %s"),
+            Map.entry("app.goodbye", "Goodbye!"));
+
+    private static volatile Lang lang = Lang.ES;
+
+    private I18n() {}
+
+    public static void setLang(Lang value) {
+        lang = value;
+    }
+
+    public static Lang getLang() {
+        return lang;
+    }
+
+    /** Message for key in the current language. */
+    static Map<String, String> catalog(Lang language) {
+        return language == Lang.ES ? ES : EN;
+    }
+
+    public static String t(String key, Object... args) {
+        return format(lang, key, args);
+    }
+
+    static String format(Lang language, String key, Object... args) {
+        return format(catalog(language), key, args);
+    }
+
+    // Lookup in the given catalog, with the English fallback applied to whatever it lacks.
+    static String format(Map<String, String> catalog, String key, Object... args) {
+        String pattern = catalog.get(key);
+        if (pattern == null) pattern = EN.get(key);
+        if (pattern == null) return key;
+        return args.length == 0 ? pattern : String.format(pattern, args);
+    }
+}
@@ -0,0 +1,62 @@
+package com.conference.client;
+
+/**
+ * Renders a minimal markdown subset to ANSI styles: *bold*, _italic_ and `code`.
+ * A backslash before a marker produces the literal character, and markers without
+ * a matching close are printed as-is. Only the displayed text is affected.
+ */
+public final class MarkdownRenderer {
+
+    private static final String BOLD = "\u001b[1m";
+    private static final String BOLD_OFF = "\u001b[22m";
+    private static final String ITALIC = "\u001b[3m";
+    private static final String ITALIC_OFF = "\u001b[23m";
+    private static final String CODE = "\u001b[36m";
+    private static final String CODE_OFF = "\u001b[39m";
+
+    private MarkdownRenderer() {}
+
+    public static String render(String text) {
+        StringBuilder out = new StringBuilder();
+        int i = 0;
+        while (i < text.length()) {
+            char c = text.charAt(i);
+            if (c == '\\' && i + 1 < text.length() && isMarker(text.charAt(i + 1))) {
+                out.append(text.charAt(i + 1));
+                i += 2;
+                continue;
+            }
+            if (isMarker(c)) {
+                int close = findClose(text, i + 1, c);
+                if (close > i + 1) {
+                    String inner = text.substring(i + 1, close);
+                    if (c == '`') out.append(CODE).append(inner).append(CODE_OFF);
+                    else if (c == '*') out.append(BOLD).append(render(inner)).append(BOLD_OFF);
+                    else out.append(ITALIC).append(render(inner)).append(ITALIC_OFF);
+                    i = close + 1;
+                    continue;
+                }
+            }
+            out.append(c);
+            i++;
+        }
+        return out.toString();
+    }
+
+    private static boolean isMarker(char c) {
+        return c == '*' || c == '_' || c == '`';
+    }
+
+    // Index of the next unescaped occurrence of marker, or -1.
+    private static int findClose(String text, int from, char marker) {
+        for (int j = from; j < text.length(); j++) {
+            char c = text.charAt(j);
+            if (c == '\\' && marker != '`') {
+                j++;
+            } else if (c == marker) {
+                return j;
+            }
+        }
+        return -1;
+    }
+}
@@ -0,0 +1,76 @@
+package com.conference.client;
+
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import javax.sound.sampled.Clip;
+import javax.sound.sampled.LineEvent;
+import java.io.File;
+import java.time.Clock;
+
+/**
+ * Short audible alert for mentions and private messages: the terminal bell by default,
+ * or a WAV file played on its own Clip so the system mixer blends it with room audio.
+ * Alerts closer together than MIN_INTERVAL_MS are dropped.
+ */
+public class MentionAlert {
+
+    /** Which incoming messages ring the terminal bell (--bell-on-message). */
+    public enum BellMode {
+        OFF, MENTION, ALL;
+
+        public static BellMode parse(String value) {
+            switch (value) {
+                case "off": return OFF;
+                case "mention": return MENTION;
+                case "all": return ALL;
+                default: return null;
+            }
+        }
+    }
+
+    static final long MIN_INTERVAL_MS = 3000;
+
+    private final String soundPath; // null means terminal bell
+    private final Clock clock;
+    private long lastAlertMillis = -MIN_INTERVAL_MS;
+
+    public MentionAlert(String soundPath, Clock clock) {
+        this.soundPath = soundPath;
+        this.clock = clock;
+    }
+
+    /** Whether a received message rings the bell; mentions include private messages. */
+    static boolean rings(BellMode mode, boolean mentioned) {
+        return mode == BellMode.ALL || (mode == BellMode.MENTION && mentioned);
+    }
+
+    synchronized boolean shouldAlert(long nowMillis) {
+        if (nowMillis - lastAlertMillis < MIN_INTERVAL_MS) {
+            return false;
+        }
+        lastAlertMillis = nowMillis;
+        return true;
+    }
+
+    public void trigger() {
+        if (!shouldAlert(clock.millis())) {
+            return;
+        }
+        if (soundPath == null) {
+            ConsoleOutput.bell();
+            return;
+        }
+        try {
+            AudioInputStream stream = AudioSystem.getAudioInputStream(new File(soundPath));
+            Clip clip = AudioSystem.getClip();
+            clip.addLineListener(event -> {
+                if (event.getType() == LineEvent.Type.STOP) clip.close();
+            });
+            clip.open(stream);
+            clip.start();
+        } catch (Exception e) {
+            // Fall back to the bell if the file can't be played
+            ConsoleOutput.bell();
+        }
+    }
+}
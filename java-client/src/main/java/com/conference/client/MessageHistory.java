@@ -0,0 +1,157 @@
+package com.conference.client;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Deque;
+import java.util.List;
+import java.util.regex.Pattern;
+
+/**
+ * Bounded in-memory record of the chat messages shown during the current session.
+ * The oldest entries are discarded once the capacity (--scrollback) is reached.
+ *
+ * It also keeps the /scroll window, which moves PAGE entries at a time. The window is
+ * counted from the newest entry; while it is scrolled up, new entries keep it on the
+ * same ones.
+ */
+public class MessageHistory {
+
+    public static final int DEFAULT_CAPACITY = 500;
+    static final int PAGE = 20;
+
+    public static class Entry {
+        private final String sender;
+        private final String content;
+        private final long timestamp;
+        private final String traceId;
+        private final boolean system;
+
+        public Entry(String sender, String content, long timestamp, String traceId) {
+            this(sender, content, timestamp, traceId, false);
+        }
+
+        /** A system entry is an event recorded by the client (a join, a leave), not a chat message. */
+        public Entry(String sender, String content, long timestamp, String traceId, boolean system) {
+            this.sender = sender;
+            this.content = content;
+            this.timestamp = timestamp;
+            this.traceId = traceId;
+            this.system = system;
+        }
+
+        public String getSender() { return sender; }
+        public String getContent() { return content; }
+        public long getTimestamp() { return timestamp; }
+        public String getTraceId() { return traceId; }
+        public boolean isSystem() { return system; }
+    }
+
+    private final Deque<Entry> entries = new ArrayDeque<>();
+    private final int capacity;
+    private int hidden = 0; // Newest entries below the /scroll window
+
+    public MessageHistory(int capacity) {
+        this.capacity = capacity;
+    }
+
+    public synchronized void add(Entry entry) {
+        if (entries.size() >= capacity) {
+            entries.removeFirst();
+        }
+        entries.addLast(entry);
+        if (hidden > 0) hidden++;
+        clamp();
+    }
+
+    public synchronized void clear() {
+        entries.clear();
+        hidden = 0;
+    }
+
+    public synchronized int size() {
+        return entries.size();
+    }
+
+    public synchronized void scrollUp() {
+        hidden += PAGE;
+        clamp();
+    }
+
+    public synchronized void scrollDown() {
+        hidden = Math.max(0, hidden - PAGE);
+    }
+
+    public synchronized void scrollTop() {
+        hidden = entries.size();
+        clamp();
+    }
+
+    public synchronized void scrollBottom() {
+        hidden = 0;
+    }
+
+    /** Entries in the /scroll window, oldest first. */
+    public synchronized List<Entry> window() {
+        List<Entry> all = new ArrayList<>(entries);
+        int end = all.size() - hidden;
+        return new ArrayList<>(all.subList(Math.max(0, end - PAGE), end));
+    }
+
+    /** Number of entries newer than the window; 0 when it shows the latest ones. */
+    public synchronized int getHidden() {
+        return hidden;
+    }
+
+    private void clamp() {
+        hidden = Math.min(hidden, Math.max(0, entries.size() - PAGE));
+    }
+
+    public synchronized List<Entry> snapshot() {
+        return new ArrayList<>(entries);
+    }
+
+    public synchronized Entry findByTraceId(String traceId) {
+        for (Entry entry : entries) {
+            if (entry.getTraceId().equals(traceId)) return entry;
+        }
+        return null;
+    }
+
+    /**
+     * Resolves a user reference to buffered messages. A number n selects the n-th most
+     * recent message; anything else is treated as a trace id prefix. More than one
+     * result means the reference is ambiguous. System entries are never selected.
+     */
+    public synchronized List<Entry> resolve(String reference) {
+        List<Entry> result = new ArrayList<>();
+        if (reference.matches("\\d{1,9}")) {
+            int index = Integer.parseInt(reference);
+            List<Entry> messages = new ArrayList<>();
+            for (Entry entry : entries) {
+                if (!entry.isSystem()) messages.add(entry);
+            }
+            if (index >= 1 && index <= messages.size()) result.add(messages.get(messages.size() - index));
+            return result;
+        }
+        for (Entry entry : entries) {
+            if (!entry.isSystem() && !entry.getTraceId().isEmpty() && entry.getTraceId().startsWith(reference)) result.add(entry);
+        }
+        return result;
+    }
+
+    public synchronized List<Entry> mentionsOf(String name) {
+        List<Entry> result = new ArrayList<>();
+        for (Entry entry : entries) {
+            if (!entry.isSystem() && mentions(entry.getContent(), name)) result.add(entry);
+        }
+        return result;
+    }
+
+    // Matches "@name" case-insensitively, only when not glued to surrounding word characters.
+    public static boolean mentions(String content, String name) {
+        if (name.isEmpty()) return false;
+        Pattern pattern = Pattern.compile("(?<![\\w@])@" + Pattern.quote(name) + "(?!\\w)",
+                Pattern.CASE_INSENSITIVE | Pattern.UNICODE_CASE | Pattern.UNICODE_CHARACTER_CLASS);
+        return pattern.matcher(content).find();
+    }
+}
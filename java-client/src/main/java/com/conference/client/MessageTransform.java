@@ -0,0 +1,13 @@
+package com.conference.client;
+
+/**
+ * Rewrites outgoing message text (room messages, replies and /msg) before it is put
+ * into the message sent to the server, e.g. to append a signature or filter words.
+ * Register with ChatClient.addMessageTransform; transforms run in registration order,
+ * each receiving the previous one's output, on the input thread.
+ */
+@FunctionalInterface
+public interface MessageTransform {
+
+    String transform(String text);
+}
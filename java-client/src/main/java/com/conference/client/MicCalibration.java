@@ -0,0 +1,110 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Microphone gain and silence threshold measured once and kept in mic_calibration.json.
+ * A short stretch of silence and a short stretch of speech are captured; the gain brings
+ * the speech level to TARGET_RMS, bounded to [MIN_GAIN, MAX_GAIN], and the threshold sits
+ * THRESHOLD_FRACTION of the way from the noise level to the speech level. If the speech
+ * is not clearly louder than the background the measurement is discarded.
+ */
+public class MicCalibration {
+
+    public static final String DEFAULT_FILE = "mic_calibration.json";
+    static final double NOISE_SECONDS = 1.0;
+    static final double SPEECH_SECONDS = 2.5;
+    static final double TARGET_RMS = 3000.0;
+    static final double MIN_GAIN = 0.5;
+    static final double MAX_GAIN = 4.0;
+    static final double MIN_SPEECH_TO_NOISE = 2.0;
+    static final double THRESHOLD_FRACTION = 0.25;
+
+    private final Path path;
+
+    public MicCalibration(Path path) {
+        this.path = path;
+    }
+
+    public MicCalibration() {
+        this(Paths.get(DEFAULT_FILE));
+    }
+
+    /** The stored gain, or null if the mic was never calibrated or the file is unreadable. */
+    public Double load() {
+        Double gain = read("gain");
+        return gain == null ? null : clamp(gain);
+    }
+
+    /** The stored silence threshold (RMS before gain), or null if none was measured. */
+    public Double loadThreshold() {
+        return read("silence_rms");
+    }
+
+    private Double read(String field) {
+        if (!Files.exists(path)) return null;
+        try {
+            String value = SimpleJson.parseObject(new String(Files.readAllBytes(path), StandardCharsets.UTF_8)).get(field);
+            return value == null ? null : Double.parseDouble(value);
+        } catch (IOException | IllegalArgumentException e) {
+            return null;
+        }
+    }
+
+    public void save(double gain, double threshold, double noiseRms, double speechRms) throws IOException {
+        Map<String, Object> fields = new LinkedHashMap<>();
+        fields.put("gain", round(gain));
+        fields.put("silence_rms", round(threshold));
+        fields.put("noise_rms", round(noiseRms));
+        fields.put("speech_rms", round(speechRms));
+        Files.write(path, (SimpleJson.object(fields) + "\n").getBytes(StandardCharsets.UTF_8));
+    }
+
+    /** Gain for the measured levels, or null if speech did not stand out from the noise. */
+    static Double computeGain(double noiseRms, double speechRms) {
+        if (speechRms <= 0 || speechRms < noiseRms * MIN_SPEECH_TO_NOISE) return null;
+        return clamp(TARGET_RMS / speechRms);
+    }
+
+    /**
+     * Level below which a captured chunk counts as silence and is not sent: between the
+     * noise and speech levels, closer to the noise so quiet syllables still get through.
+     */
+    static double computeThreshold(double noiseRms, double speechRms) {
+        return noiseRms + (speechRms - noiseRms) * THRESHOLD_FRACTION;
+    }
+
+    /** Background level: the median chunk, so a single click does not count as noise. */
+    static double noiseLevel(List<Double> chunkRms) {
+        return percentile(chunkRms, 0.5);
+    }
+
+    /** Speech level: the 90th percentile chunk, since pauses between words are mostly silence. */
+    static double speechLevel(List<Double> chunkRms) {
+        return percentile(chunkRms, 0.9);
+    }
+
+    private static double percentile(List<Double> values, double fraction) {
+        if (values.isEmpty()) return 0;
+        List<Double> sorted = new ArrayList<>(values);
+        Collections.sort(sorted);
+        return sorted.get((int) Math.min(sorted.size() - 1, Math.floor(fraction * sorted.size())));
+    }
+
+    private static double clamp(double gain) {
+        return Math.max(MIN_GAIN, Math.min(MAX_GAIN, gain));
+    }
+
+    private static double round(double value) {
+        return Math.round(value * 100) / 100.0;
+    }
+}
@@ -0,0 +1,46 @@
+package com.conference.client;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Collects the lines typed after /multiline until a terminator (a lone "." or a
+ * blank line) and joins them into a single message with embedded newlines.
+ */
+public class MultilineComposer {
+
+    private final List<String> lines = new ArrayList<>();
+    private boolean done = false;
+
+    /** Adds a typed line; returns true once the terminator has been seen. */
+    public boolean accept(String line) {
+        if (done) return true;
+        if (line.trim().isEmpty() || line.trim().equals(".")) {
+            done = true;
+        } else {
+            lines.add(line);
+        }
+        return done;
+    }
+
+    public boolean isEmpty() {
+        return lines.isEmpty();
+    }
+
+    public String text() {
+        return String.join("\n", lines);
+    }
+
+    /**
+     * Prepares multi-line content for display after a prefix of the given width:
+     * continuation lines are indented under the first one, and carriage returns are
+     * dropped so a message can't rewrite the line and pass itself off as a prompt.
+     */
+    public static String indentContinuation(String text, int width) {
+        String clean = text.replace("\r", "");
+        if (clean.indexOf('\n') < 0) return clean;
+        StringBuilder indent = new StringBuilder("\n");
+        for (int i = 0; i < width; i++) indent.append(' ');
+        return clean.replace("\n", indent.toString());
+    }
+}
@@ -0,0 +1,64 @@
+package com.conference.client;
+
+import com.conference.grpc.ConferenceData;
+import com.google.protobuf.ByteString;
+import com.google.protobuf.TextFormat;
+import io.grpc.Status;
+import io.grpc.stub.StreamObserver;
+
+import java.io.PrintStream;
+
+/**
+ * Developer trace for the hidden --dump-proto flag: wraps one direction of the
+ * conference stream and writes every ConferenceData passing through it on one line,
+ * in protobuf text form, before handing it on. Audio payloads are summarized by
+ * length and first bytes instead of being dumped.
+ */
+final class ProtoDump implements StreamObserver<ConferenceData> {
+
+    private static final int AUDIO_PREVIEW_BYTES = 8;
+
+    private final String direction;
+    private final StreamObserver<ConferenceData> delegate;
+    private final PrintStream out;
+
+    ProtoDump(String direction, StreamObserver<ConferenceData> delegate, PrintStream out) {
+        this.direction = direction;
+        this.delegate = delegate;
+        this.out = out;
+    }
+
+    @Override
+    public void onNext(ConferenceData data) {
+        out.println(format(direction, data));
+        delegate.onNext(data);
+    }
+
+    @Override
+    public void onError(Throwable t) {
+        out.println(direction + " error " + Status.fromThrowable(t));
+        delegate.onError(t);
+    }
+
+    @Override
+    public void onCompleted() {
+        out.println(direction + " completed");
+        delegate.onCompleted();
+    }
+
+    static String format(String direction, ConferenceData data) {
+        TextFormat.Printer printer = TextFormat.printer();
+        if (data.getPayloadCase() != ConferenceData.PayloadCase.AUDIO_CHUNK) {
+            return direction + " " + printer.shortDebugString(data);
+        }
+        ByteString payload = data.getAudioChunk().getData();
+        ConferenceData withoutPayload = data.toBuilder()
+                .setAudioChunk(data.getAudioChunk().toBuilder().clearData()).build();
+        StringBuilder preview = new StringBuilder();
+        for (int i = 0; i < Math.min(AUDIO_PREVIEW_BYTES, payload.size()); i++) {
+            preview.append(String.format(" %02x", payload.byteAt(i)));
+        }
+        return direction + " " + printer.shortDebugString(withoutPayload)
+                + " [audio " + payload.size() + " bytes:" + preview + (payload.size() > AUDIO_PREVIEW_BYTES ? " ..." : "") + "]";
+    }
+}
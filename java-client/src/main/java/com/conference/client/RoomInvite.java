@@ -0,0 +1,131 @@
+package com.conference.client;
+
+import java.net.URLDecoder;
+import java.net.URLEncoder;
+import java.nio.charset.StandardCharsets;
+
+/**
+ * Shareable room links of the form elo330://host:port/room?name=user, with IPv6 hosts
+ * in brackets. /invite prints one, and the client accepts one as its positional argument to connect directly.
+ */
+public final class RoomInvite {
+
+    public static final String SCHEME = "elo330://";
+
+    private final String host;
+    private final int port;
+    private final String room;
+    private final String name;
+
+    public RoomInvite(String host, int port, String room, String name) {
+        this.host = host;
+        this.port = port;
+        this.room = room;
+        this.name = name;
+    }
+
+    public static boolean isInvite(String value) {
+        return value.regionMatches(true, 0, SCHEME, 0, SCHEME.length());
+    }
+
+    public String toUri() {
+        String authority = host.contains(":") ? "[" + host + "]" : host; // IPv6 literals are bracketed
+        String uri = SCHEME + authority + ":" + port + "/" + encode(room);
+        return name == null ? uri : uri + "?name=" + encode(name);
+    }
+
+    public static RoomInvite parse(String uri) {
+        if (!isInvite(uri)) {
+            throw new IllegalArgumentException("El enlace debe empezar con " + SCHEME + ": " + uri);
+        }
+        String rest = uri.substring(SCHEME.length());
+        String query = null;
+        int question = rest.indexOf('?');
+        if (question >= 0) {
+            query = rest.substring(question + 1);
+            rest = rest.substring(0, question);
+        }
+        int slash = rest.indexOf('/');
+        if (slash <= 0) {
+            throw new IllegalArgumentException("Falta el servidor o la sala en el enlace: " + uri);
+        }
+        String authority = rest.substring(0, slash);
+        String room = decode(rest.substring(slash + 1));
+        if (room.trim().isEmpty()) {
+            throw new IllegalArgumentException("Falta la sala en el enlace: " + uri);
+        }
+
+        String host = authority;
+        String portText = null;
+        if (authority.startsWith("[")) {
+            int close = authority.indexOf(']');
+            String after = close < 0 ? "" : authority.substring(close + 1);
+            if (close < 0 || !(after.isEmpty() || after.startsWith(":"))) {
+                throw new IllegalArgumentException("Dirección IPv6 inválida en el enlace: " + uri);
+            }
+            host = authority.substring(1, close);
+            if (!after.isEmpty()) portText = after.substring(1);
+        } else {
+            int colon = authority.lastIndexOf(':');
+            if (colon >= 0) {
+                host = authority.substring(0, colon);
+                portText = authority.substring(colon + 1);
+            }
+        }
+        int port = ClientOptions.DEFAULT_PORT;
+        if (portText != null) {
+            try {
+                port = Integer.parseInt(portText);
+            } catch (NumberFormatException e) {
+                throw new IllegalArgumentException("Puerto inválido en el enlace: " + uri);
+            }
+            if (port < 1 || port > 65535) {
+                throw new IllegalArgumentException("Puerto inválido en el enlace: " + uri);
+            }
+        }
+        if (host.isEmpty()) {
+            throw new IllegalArgumentException("Falta el servidor en el enlace: " + uri);
+        }
+
+        String name = null;
+        if (query != null) {
+            for (String param : query.split("&")) {
+                if (param.startsWith("name=")) {
+                    name = decode(param.substring("name=".length()));
+                }
+            }
+        }
+        if (name != null && name.trim().isEmpty()) name = null;
+        return new RoomInvite(host, port, room, name);
+    }
+
+    private static String encode(String value) {
+        return URLEncoder.encode(value, StandardCharsets.UTF_8).replace("+", "%20");
+    }
+
+    // Percent-decoding only: a literal '+' stays a '+' rather than becoming a space.
+    private static String decode(String value) {
+        try {
+            return URLDecoder.decode(value.replace("+", "%2B"), StandardCharsets.UTF_8);
+        } catch (IllegalArgumentException e) {
+            throw new IllegalArgumentException("Codificación inválida en el enlace: " + value);
+        }
+    }
+
+    public String getHost() {
+        return host;
+    }
+
+    public int getPort() {
+        return port;
+    }
+
+    public String getRoom() {
+        return room;
+    }
+
+    /** Suggested user name, or null when the link has none. */
+    public String getName() {
+        return name;
+    }
+}
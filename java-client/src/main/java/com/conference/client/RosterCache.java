@@ -0,0 +1,100 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.time.Clock;
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Who was seen in each room and when, kept in roster.json across sessions (opt-in
+ * with --roster-cache). Updated from joins, leaves and received messages; entries
+ * older than MAX_AGE_SECONDS are dropped and each room keeps at most MAX_PER_ROOM
+ * names, most recent first.
+ */
+public class RosterCache {
+
+    public static final String DEFAULT_FILE = "roster.json";
+    static final int MAX_PER_ROOM = 100;
+    static final long MAX_AGE_SECONDS = 30L * 24 * 3600;
+
+    private final Path path;
+    private final Clock clock;
+    private final Map<String, Map<String, Long>> rooms = new HashMap<>();
+
+    public RosterCache(Path path, Clock clock) {
+        this.path = path;
+        this.clock = clock;
+    }
+
+    public RosterCache(Clock clock) {
+        this(Paths.get(DEFAULT_FILE), clock);
+    }
+
+    public synchronized void load() throws IOException {
+        rooms.clear();
+        if (!Files.exists(path)) return;
+        try {
+            for (Map<String, String> row : SimpleJson.parseArray(new String(Files.readAllBytes(path), StandardCharsets.UTF_8))) {
+                String room = row.get("room_id");
+                String name = row.get("name");
+                if (room == null || name == null) continue;
+                rooms.computeIfAbsent(room, k -> new HashMap<>()).put(name, Long.parseLong(row.getOrDefault("last_seen", "0")));
+            }
+        } catch (IllegalArgumentException e) {
+            throw new IOException("formato inválido en " + path + ": " + e.getMessage());
+        }
+        // A hand-edited file may hold more, or older, names than the cache keeps
+        for (String room : new ArrayList<>(rooms.keySet())) {
+            rooms.put(room, new HashMap<>(lastSeen(room)));
+        }
+    }
+
+    public synchronized void save() throws IOException {
+        List<Map<String, String>> rows = new ArrayList<>();
+        for (Map.Entry<String, Map<String, Long>> room : rooms.entrySet()) {
+            for (Map.Entry<String, Long> member : lastSeen(room.getKey()).entrySet()) {
+                Map<String, String> row = new LinkedHashMap<>();
+                row.put("room_id", room.getKey());
+                row.put("name", member.getKey());
+                row.put("last_seen", Long.toString(member.getValue()));
+                rows.add(row);
+            }
+        }
+        Files.write(path, SimpleJson.array(rows).getBytes(StandardCharsets.UTF_8));
+    }
+
+    public synchronized void seen(String roomId, String name) {
+        Map<String, Long> members = rooms.computeIfAbsent(roomId, k -> new HashMap<>());
+        members.put(name, clock.instant().getEpochSecond());
+        if (members.size() > MAX_PER_ROOM) {
+            members.entrySet().stream()
+                    .filter(e -> !e.getKey().equals(name))
+                    .min(Map.Entry.comparingByValue())
+                    .ifPresent(oldest -> members.remove(oldest.getKey()));
+        }
+    }
+
+    /** Names held in memory for the room, expired ones included. */
+    synchronized int memberCount(String roomId) {
+        return rooms.getOrDefault(roomId, Map.of()).size();
+    }
+
+    /** Members of the room with their last-seen time (epoch seconds), most recent first, pruned. */
+    public synchronized Map<String, Long> lastSeen(String roomId) {
+        long cutoff = clock.instant().getEpochSecond() - MAX_AGE_SECONDS;
+        Map<String, Long> result = new LinkedHashMap<>();
+        rooms.getOrDefault(roomId, Map.of()).entrySet().stream()
+                .filter(e -> e.getValue() >= cutoff)
+                .sorted(Map.Entry.<String, Long>comparingByValue().reversed())
+                .limit(MAX_PER_ROOM)
+                .forEach(e -> result.put(e.getKey(), e.getValue()));
+        return result;
+    }
+}
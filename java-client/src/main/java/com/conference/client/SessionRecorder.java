@@ -0,0 +1,84 @@
+package com.conference.client;
+
+import java.io.BufferedWriter;
+import java.io.IOException;
+import java.io.Writer;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.time.Clock;
+import java.util.LinkedHashMap;
+import java.util.Map;
+
+/**
+ * Writes every client event to a JSON-lines file (--record-session) so the session
+ * can be shown again with the `replay` subcommand. Each line is a flat object with
+ * the wall-clock time in "at" (epoch milliseconds), the event name and its fields.
+ */
+public class SessionRecorder implements ClientEventListener, AutoCloseable {
+
+    private final BufferedWriter writer;
+    private final Clock clock;
+
+    public SessionRecorder(Path path, Clock clock) throws IOException {
+        this(Files.newBufferedWriter(path, StandardCharsets.UTF_8), clock);
+    }
+
+    /** Writes the same event lines to any writer; ControlServer uses it for its connections. */
+    SessionRecorder(Writer writer, Clock clock) {
+        this.writer = writer instanceof BufferedWriter ? (BufferedWriter) writer : new BufferedWriter(writer);
+        this.clock = clock;
+    }
+
+    @Override public void onConnected(String sender, String roomId) {
+        write("connected", "sender", sender, "room_id", roomId);
+    }
+
+    @Override public void onDisconnected(String roomId) {
+        write("disconnected", "room_id", roomId);
+    }
+
+    @Override public void onMessageReceived(MessageHistory.Entry message) {
+        write("message_received", "sender", message.getSender(), "content", message.getContent(), "trace_id", message.getTraceId());
+    }
+
+    @Override public void onMessageSent(MessageHistory.Entry message) {
+        write("message_sent", "sender", message.getSender(), "content", message.getContent(), "trace_id", message.getTraceId());
+    }
+
+    @Override public void onUserJoined(String name) {
+        write("user_joined", "name", name);
+    }
+
+    @Override public void onUserLeft(String name) {
+        write("user_left", "name", name);
+    }
+
+    @Override public void onAudioChanged(boolean transmitting) {
+        write("audio", "transmitting", Boolean.toString(transmitting));
+    }
+
+    @Override public void onCommand(String commandLine) {
+        write("command", "line", commandLine);
+    }
+
+    // Events come from the gRPC and input threads; each line is written whole and flushed.
+    synchronized void write(String event, String... fields) {
+        Map<String, Object> line = new LinkedHashMap<>();
+        line.put("at", clock.millis());
+        line.put("event", event);
+        for (int i = 0; i + 1 < fields.length; i += 2) line.put(fields[i], fields[i + 1]);
+        try {
+            writer.write(SimpleJson.object(line));
+            writer.newLine();
+            writer.flush();
+        } catch (IOException e) {
+            System.err.println("⚠️ No se pudo escribir la grabación de la sesión: " + e.getMessage());
+        }
+    }
+
+    @Override
+    public synchronized void close() throws IOException {
+        writer.close();
+    }
+}
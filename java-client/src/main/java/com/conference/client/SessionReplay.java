@@ -0,0 +1,89 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.LocalDateTime;
+import java.time.ZoneId;
+import java.time.format.DateTimeFormatter;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Plays back a file written by --record-session, printing each event as a display
+ * line with the original gaps between events divided by the speed factor (0 prints
+ * everything at once). Used by the `replay` subcommand; returns the exit code. Times
+ * are shown in the clock's zone.
+ */
+public class SessionReplay {
+
+    private static final DateTimeFormatter TIME_FORMATTER = DateTimeFormatter.ofPattern("HH:mm:ss");
+
+    public static int run(Path path, int speed, Clock clock) {
+        List<String> lines;
+        try {
+            lines = Files.readAllLines(path, StandardCharsets.UTF_8);
+        } catch (IOException e) {
+            System.err.println(I18n.t("replay.read_error", path, e.getMessage()));
+            return 1;
+        }
+        long previous = -1;
+        try {
+            for (int i = 0; i < lines.size(); i++) {
+                if (lines.get(i).trim().isEmpty()) continue;
+                Map<String, String> event;
+                long at;
+                try {
+                    event = SimpleJson.parseObject(lines.get(i));
+                    at = Long.parseLong(event.getOrDefault("at", "0"));
+                } catch (IllegalArgumentException e) {
+                    System.err.println(I18n.t("replay.invalid_line", i + 1, e.getMessage()));
+                    return 1;
+                }
+                if (speed > 0 && previous >= 0 && at > previous) {
+                    Thread.sleep((at - previous) / speed);
+                }
+                previous = at;
+                String line = formatEvent(event, at, clock.getZone());
+                if (line != null) ConsoleOutput.printLine(line);
+            }
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return 1;
+        }
+        return 0;
+    }
+
+    /** Display line for a recorded event, or null for unknown events. */
+    static String formatEvent(Map<String, String> event, long at, ZoneId zone) {
+        String time = "[" + LocalDateTime.ofInstant(Instant.ofEpochMilli(at), zone).format(TIME_FORMATTER) + "] ";
+        switch (event.getOrDefault("event", "")) {
+            case "connected":
+                return time + I18n.t("replay.connected", ChatClient.displayName(String.valueOf(event.get("sender"))),
+                        ChatClient.displayName(String.valueOf(event.get("room_id"))));
+            case "disconnected":
+                return time + I18n.t("replay.disconnected", ChatClient.displayName(String.valueOf(event.get("room_id"))));
+            case "message_received":
+                return withContent(time + ChatClient.displayName(String.valueOf(event.get("sender"))) + ": ", event.get("content"));
+            case "message_sent":
+                return withContent(time + I18n.t("self.label") + ": ", event.get("content"));
+            case "user_joined":
+                return time + "[SERVER] USER_JOINED: " + ChatClient.displayName(String.valueOf(event.get("name")));
+            case "user_left":
+                return time + "[SERVER] USER_LEFT: " + ChatClient.displayName(String.valueOf(event.get("name")));
+            case "audio":
+                return time + I18n.t("true".equals(event.get("transmitting")) ? "replay.mic_on" : "replay.mic_off");
+            case "command":
+                return time + "> " + ConsoleOutput.sanitize(String.valueOf(event.get("line")), Integer.MAX_VALUE);
+            default:
+                return null;
+        }
+    }
+
+    private static String withContent(String prefix, String content) {
+        return prefix + MultilineComposer.indentContinuation(ConsoleOutput.sanitizeText(String.valueOf(content)), prefix.length());
+    }
+}
@@ -0,0 +1,115 @@
+package com.conference.client;
+
+/**
+ * Traffic counters for the current client session. All methods are synchronized so
+ * that a reset never interleaves with an update and a snapshot is always consistent.
+ */
+public class SessionStats {
+
+    // Shown by /stats and cleared by /stats reset
+    private long messagesSent;
+    private long messagesReceived;
+    private long duplicatesDropped;
+    private long audioChunksSent;
+    private long audioChunksReceived;
+    private long audioBytesSent;
+    private long audioBytesReceived;
+    private long audioChunksRejected;
+
+    // Totals for the exit summary, never reset
+    private long totalMessagesSent;
+    private long totalMessagesReceived;
+    private long audioFramesSent;
+    private long audioFramesReceived;
+    private long reconnects;
+    private long connectedMillis;
+    private long latencySamples;
+    private long latencyTotalMillis;
+    private long latencyPeakMillis;
+
+    public synchronized void messageSent() {
+        messagesSent++;
+        totalMessagesSent++;
+    }
+
+    public synchronized void messageReceived() {
+        messagesReceived++;
+        totalMessagesReceived++;
+    }
+
+    public synchronized void duplicateDropped() { duplicatesDropped++; }
+
+    public synchronized void audioSent(int bytes, int channels) {
+        audioChunksSent++;
+        audioBytesSent += bytes;
+        audioFramesSent += bytes / (2 * Math.max(1, channels)); // 16-bit samples
+    }
+
+    public synchronized void audioReceived(int bytes, int channels) {
+        audioChunksReceived++;
+        audioBytesReceived += bytes;
+        audioFramesReceived += bytes / (2 * Math.max(1, channels));
+    }
+
+    public synchronized void reconnected() { reconnects++; }
+    public synchronized void connectedFor(long millis) { connectedMillis += millis; }
+
+    public synchronized void latency(long millis) {
+        latencySamples++;
+        latencyTotalMillis += millis;
+        latencyPeakMillis = Math.max(latencyPeakMillis, millis);
+    }
+
+    public synchronized void audioRejected() { audioChunksRejected++; }
+
+    /** Clears the counters shown by format(); the totals behind formatSummary() are kept. */
+    public synchronized void reset() {
+        messagesSent = 0;
+        messagesReceived = 0;
+        duplicatesDropped = 0;
+        audioChunksSent = 0;
+        audioChunksReceived = 0;
+        audioBytesSent = 0;
+        audioBytesReceived = 0;
+        audioChunksRejected = 0;
+    }
+
+    public synchronized long getMessagesSent() { return messagesSent; }
+    public synchronized long getMessagesReceived() { return messagesReceived; }
+    public synchronized long getAudioBytesSent() { return audioBytesSent; }
+    public synchronized long getAudioBytesReceived() { return audioBytesReceived; }
+    public synchronized long getAudioChunksRejected() { return audioChunksRejected; }
+
+    public synchronized String format() {
+        return String.join("\n",
+                I18n.t("stats.title"),
+                String.format("  %-22s %d", I18n.t("stats.sent"), messagesSent),
+                String.format("  %-22s %d", I18n.t("stats.received"), messagesReceived),
+                String.format("  %-22s %d", I18n.t("stats.duplicates"), duplicatesDropped),
+                String.format("  %-22s %d (%.1f KiB)", I18n.t("stats.audio_sent"), audioChunksSent, audioBytesSent / 1024.0),
+                String.format("  %-22s %d (%.1f KiB)", I18n.t("stats.audio_received"), audioChunksReceived, audioBytesReceived / 1024.0),
+                String.format("  %-22s %d", I18n.t("stats.audio_rejected"), audioChunksRejected));
+    }
+
+    /** Wrap-up printed when the client exits. Latency comes from /echo probes, if any were made. */
+    public synchronized String formatSummary() {
+        long seconds = connectedMillis / 1000;
+        String latency = latencySamples == 0 ? I18n.t("summary.latency_none")
+                : I18n.t("summary.latency_value", latencyTotalMillis / latencySamples, latencyPeakMillis);
+        return String.join("\n",
+                I18n.t("summary.title"),
+                String.format("  %-22s %d:%02d:%02d", I18n.t("summary.connected"), seconds / 3600, seconds / 60 % 60, seconds % 60),
+                String.format("  %-22s %d / %d", I18n.t("summary.messages"), totalMessagesSent, totalMessagesReceived),
+                String.format("  %-22s %.1f s / %.1f s", I18n.t("summary.audio"),
+                        audioFramesSent / (double) AudioStreamer.SAMPLE_RATE, audioFramesReceived / (double) AudioStreamer.SAMPLE_RATE),
+                String.format("  %-22s %d", I18n.t("summary.reconnects"), reconnects),
+                String.format("  %-22s %s", I18n.t("summary.latency"), latency));
+    }
+
+    // Single-line form for periodic logging.
+    public synchronized String formatCompact() {
+        return String.format("sent=%d received=%d duplicates=%d audio_sent=%d/%dB audio_received=%d/%dB audio_rejected=%d",
+                messagesSent, messagesReceived, duplicatesDropped,
+                audioChunksSent, audioBytesSent, audioChunksReceived, audioBytesReceived, audioChunksRejected);
+    }
+}
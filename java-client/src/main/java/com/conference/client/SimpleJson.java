@@ -0,0 +1,145 @@
+package com.conference.client;
+
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Minimal JSON support for the client's small local files: arrays of flat objects whose
+ * values are strings or numbers. Not a general-purpose parser.
+ */
+public final class SimpleJson {
+
+    private SimpleJson() {}
+
+    public static String quote(String value) {
+        StringBuilder sb = new StringBuilder("\"");
+        for (char c : value.toCharArray()) {
+            switch (c) {
+                case '"': sb.append("\\\""); break;
+                case '\\': sb.append("\\\\"); break;
+                case '\n': sb.append("\\n"); break;
+                case '\r': sb.append("\\r"); break;
+                case '\t': sb.append("\\t"); break;
+                default:
+                    if (c < 0x20) sb.append(String.format("\\u%04x", (int) c));
+                    else sb.append(c);
+            }
+        }
+        return sb.append('"').toString();
+    }
+
+    public static String object(Map<String, ?> fields) {
+        StringBuilder sb = new StringBuilder("{");
+        for (Map.Entry<String, ?> field : fields.entrySet()) {
+            if (sb.length() > 1) sb.append(", ");
+            sb.append(quote(field.getKey())).append(": ");
+            Object value = field.getValue();
+            sb.append(value instanceof Number ? value.toString() : quote(String.valueOf(value)));
+        }
+        return sb.append('}').toString();
+    }
+
+    public static String array(List<Map<String, String>> objects) {
+        StringBuilder sb = new StringBuilder("[\n");
+        for (int i = 0; i < objects.size(); i++) {
+            sb.append("  ").append(object(objects.get(i)));
+            sb.append(i < objects.size() - 1 ? ",\n" : "\n");
+        }
+        return sb.append("]\n").toString();
+    }
+
+    /** Parses an array of flat objects. All values are returned as strings. */
+    public static List<Map<String, String>> parseArray(String json) {
+        Parser parser = new Parser(json);
+        List<Map<String, String>> result = new ArrayList<>();
+        parser.expect('[');
+        if (parser.peek() == ']') return result;
+        do {
+            result.add(parser.parseObject());
+        } while (parser.consumeIf(','));
+        parser.expect(']');
+        return result;
+    }
+
+    /** Parses a single flat object, e.g. one line of a JSON-lines file. */
+    public static Map<String, String> parseObject(String json) {
+        return new Parser(json).parseObject();
+    }
+
+    private static class Parser {
+        private final String text;
+        private int pos = 0;
+
+        Parser(String text) { this.text = text; }
+
+        char peek() {
+            while (pos < text.length() && Character.isWhitespace(text.charAt(pos))) pos++;
+            if (pos >= text.length()) throw new IllegalArgumentException("JSON incompleto");
+            return text.charAt(pos);
+        }
+
+        void expect(char c) {
+            if (peek() != c) throw new IllegalArgumentException("Se esperaba '" + c + "' en la posición " + pos);
+            pos++;
+        }
+
+        boolean consumeIf(char c) {
+            if (peek() != c) return false;
+            pos++;
+            return true;
+        }
+
+        Map<String, String> parseObject() {
+            Map<String, String> fields = new LinkedHashMap<>();
+            expect('{');
+            if (consumeIf('}')) return fields;
+            do {
+                String key = parseString();
+                expect(':');
+                fields.put(key, peek() == '"' ? parseString() : parseBare());
+            } while (consumeIf(','));
+            expect('}');
+            return fields;
+        }
+
+        String parseString() {
+            expect('"');
+            StringBuilder sb = new StringBuilder();
+            while (pos < text.length()) {
+                char c = text.charAt(pos++);
+                if (c == '"') return sb.toString();
+                if (c != '\\') { sb.append(c); continue; }
+                if (pos >= text.length()) throw new IllegalArgumentException("Secuencia de escape sin terminar");
+                char esc = text.charAt(pos++);
+                switch (esc) {
+                    case 'n': sb.append('\n'); break;
+                    case 'r': sb.append('\r'); break;
+                    case 't': sb.append('\t'); break;
+                    case 'u': sb.append(parseUnicodeEscape()); break;
+                    default: sb.append(esc);
+                }
+            }
+            throw new IllegalArgumentException("Cadena JSON sin cerrar");
+        }
+
+        char parseUnicodeEscape() {
+            if (pos + 4 > text.length()) throw new IllegalArgumentException("Secuencia de escape sin terminar");
+            int value = 0;
+            for (int i = 0; i < 4; i++) {
+                int digit = Character.digit(text.charAt(pos + i), 16);
+                if (digit < 0) throw new IllegalArgumentException("Escape \\u no válido: " + text.substring(pos, pos + 4));
+                value = value * 16 + digit;
+            }
+            pos += 4;
+            return (char) value;
+        }
+
+        String parseBare() {
+            int start = pos;
+            while (pos < text.length() && ",}] \n\r\t".indexOf(text.charAt(pos)) < 0) pos++;
+            return text.substring(start, pos);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+package com.conference.client;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.time.Instant;
+import java.time.ZoneId;
+import java.time.format.DateTimeFormatter;
+import java.util.List;
+import java.util.Locale;
+
+/**
+ * Writes the session's message history to a Markdown or HTML file for /export, the
+ * format chosen by the file extension. System entries (joins and leaves) are styled
+ * as events. Message text is escaped so it cannot inject markup.
+ */
+public final class TranscriptExport {
+
+    private static final DateTimeFormatter FORMATTER = DateTimeFormatter.ofPattern("yyyy-MM-dd HH:mm");
+    private static final String MARKDOWN_SPECIAL = "\\`*_[]<>#|&";
+
+    private TranscriptExport() {}
+
+    /** Writes the transcript; throws IllegalArgumentException for an extension other than .md or .html. */
+    public static void write(Path path, String roomId, List<MessageHistory.Entry> entries, ZoneId zone) throws IOException {
+        String name = path.getFileName().toString().toLowerCase(Locale.ROOT);
+        String text;
+        if (name.endsWith(".md")) {
+            text = toMarkdown(roomId, entries, zone);
+        } else if (name.endsWith(".html") || name.endsWith(".htm")) {
+            text = toHtml(roomId, entries, zone);
+        } else {
+            throw new IllegalArgumentException("la extensión debe ser .md o .html");
+        }
+        Files.write(path, text.getBytes(StandardCharsets.UTF_8));
+    }
+
+    static String toMarkdown(String roomId, List<MessageHistory.Entry> entries, ZoneId zone) {
+        StringBuilder sb = new StringBuilder("# Sala ").append(escapeMarkdown(roomId)).append("\n\n");
+        for (MessageHistory.Entry entry : entries) {
+            // Trailing double space keeps the message's line breaks inside the list item
+            String content = escapeMarkdown(entry.getContent()).replace("\n", "  \n  ");
+            sb.append("- `").append(time(entry, zone)).append("` ");
+            if (entry.isSystem()) {
+                sb.append('*').append(content).append("*\n");
+            } else {
+                sb.append("**").append(escapeMarkdown(entry.getSender())).append(":** ").append(content).append('\n');
+            }
+        }
+        return sb.toString();
+    }
+
+    static String toHtml(String roomId, List<MessageHistory.Entry> entries, ZoneId zone) {
+        StringBuilder sb = new StringBuilder("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n")
+                .append("<title>Sala ").append(escapeHtml(roomId)).append("</title>\n")
+                .append("<style>.time{color:#888}.system{color:#666;font-style:italic}.text{white-space:pre-wrap}</style>\n")
+                .append("</head>\n<body>\n<h1>Sala ").append(escapeHtml(roomId)).append("</h1>\n<ul>\n");
+        for (MessageHistory.Entry entry : entries) {
+            sb.append("<li").append(entry.isSystem() ? " class=\"system\"" : "").append('>')
+                    .append("<span class=\"time\">").append(time(entry, zone)).append("</span> ");
+            if (!entry.isSystem()) sb.append("<b>").append(escapeHtml(entry.getSender())).append(":</b> ");
+            sb.append("<span class=\"text\">").append(escapeHtml(entry.getContent())).append("</span></li>\n");
+        }
+        return sb.append("</ul>\n</body>\n</html>\n").toString();
+    }
+
+    static String escapeHtml(String text) {
+        return text.replace("&", "&amp;").replace("<", "&lt;").replace(">", "&gt;")
+                .replace("\"", "&quot;").replace("'", "&#39;");
+    }
+
+    static String escapeMarkdown(String text) {
+        StringBuilder sb = new StringBuilder();
+        for (char c : text.toCharArray()) {
+            if (MARKDOWN_SPECIAL.indexOf(c) >= 0) sb.append('\\');
+            sb.append(c);
+        }
+        return sb.toString();
+    }
+
+    private static String time(MessageHistory.Entry entry, ZoneId zone) {
+        return FORMATTER.format(Instant.ofEpochSecond(entry.getTimestamp()).atZone(zone));
+    }
+}
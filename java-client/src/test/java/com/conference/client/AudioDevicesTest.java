@@ -0,0 +1,59 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.TargetDataLine;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class AudioDevicesTest {
+
+    private static final List<String> DEVICES = List.of("Built-in Microphone", "USB Headset", "HDMI Output");
+
+    @Test
+    void firstMatchingPriorityWins() {
+        assertEquals("USB Headset", AudioDevices.resolvePriority(DEVICES, List.of("headset", "built-in")));
+        assertEquals("Built-in Microphone", AudioDevices.resolvePriority(DEVICES, List.of("built-in", "headset")));
+        assertEquals("USB Headset", AudioDevices.resolvePriority(DEVICES, List.of("bluetooth", "headset")));
+    }
+
+    @Test
+    void prioritiesMatchPartOfTheNameIgnoringCase() {
+        assertEquals("HDMI Output", AudioDevices.resolvePriority(DEVICES, List.of("hdmi")));
+        assertEquals("Built-in Microphone", AudioDevices.resolvePriority(DEVICES, List.of("MICRO")));
+    }
+
+    @Test
+    void noMatchFallsBackToTheDefaultDevice() {
+        assertNull(AudioDevices.resolvePriority(DEVICES, List.of("bluetooth")));
+        assertNull(AudioDevices.resolvePriority(DEVICES, List.of()));
+        AudioFormat format = new AudioFormat(AudioStreamer.SAMPLE_RATE, 16, 1, true, false);
+        assertNull(AudioDevices.select(TargetDataLine.class, format, List.of()));
+        assertNull(AudioDevices.select(TargetDataLine.class, format, List.of("no such device ☃")));
+    }
+
+    @Test
+    void findMatchesIgnoresCaseAndAccents() {
+        List<String> devices = List.of("Micrófono USB", "Altavoces HDMI");
+        assertEquals(List.of("Micrófono USB"), AudioDevices.findMatches(devices, "microfono usb"));
+        assertEquals(List.of("Micrófono USB"), AudioDevices.findMatches(devices, "MICRÓFONO"));
+        // Same name with the accent as a combining mark (NFD)
+        assertEquals(List.of("Micrófono USB"), AudioDevices.findMatches(devices, "Micro\u0301fono"));
+        assertEquals(List.of(), AudioDevices.findMatches(devices, "bluetooth"));
+    }
+
+    @Test
+    void findMatchesReturnsEveryCandidate() {
+        List<String> devices = List.of("USB Headset", "USB Microphone", "HDMI Output");
+        assertEquals(List.of("USB Headset", "USB Microphone"), AudioDevices.findMatches(devices, "usb"));
+        assertEquals(List.of("USB Headset"), AudioDevices.findMatches(List.of("USB Headset", "USB Headset 2"), " usb headset "));
+    }
+
+    @Test
+    void parsePriorityListSkipsBlankItems() {
+        assertEquals(List.of("Headset", "Built-in"), AudioDevices.parsePriorityList(" Headset, ,Built-in,"));
+        assertEquals(List.of(), AudioDevices.parsePriorityList(null));
+    }
+}
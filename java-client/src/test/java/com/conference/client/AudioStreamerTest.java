@@ -0,0 +1,114 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class AudioStreamerTest {
+
+    private static short sample(byte[] pcm, int index) {
+        return (short) ((pcm[2 * index] & 0xFF) | (pcm[2 * index + 1] << 8));
+    }
+
+    @Test
+    void toneHasTwoBytesPerSampleForTheRequestedDuration() {
+        byte[] tone = AudioStreamer.generateTone(440, 0.5, 8000);
+        assertEquals(8000, tone.length);
+    }
+
+    @Test
+    void toneStartsAtZeroAndStaysWithinHalfAmplitude() {
+        byte[] tone = AudioStreamer.generateTone(1000, 0.1, 8000);
+        assertEquals(0, sample(tone, 0));
+        int peak = 0;
+        for (int i = 0; i < tone.length / 2; i++) {
+            peak = Math.max(peak, Math.abs(sample(tone, i)));
+        }
+        assertTrue(peak <= Short.MAX_VALUE / 2);
+        assertTrue(peak > Short.MAX_VALUE / 2 - 100, "peak " + peak);
+    }
+
+    @Test
+    void toneQuarterPeriodReachesThePeak() {
+        // 2000 Hz at 8000 Hz: one period every 4 samples, so sample 1 is the positive peak
+        byte[] tone = AudioStreamer.generateTone(2000, 0.01, 8000);
+        assertEquals(Short.MAX_VALUE / 2, sample(tone, 1));
+        assertEquals(-(Short.MAX_VALUE / 2), sample(tone, 3));
+    }
+
+    @Test
+    void toneFrequencyMatchesItsZeroCrossings() {
+        int[][] cases = {{440, 16000}, {1000, 44100}, {300, 8000}};
+        for (int[] c : cases) {
+            byte[] tone = AudioStreamer.generateTone(c[0], 1.0, c[1]);
+            int rising = 0;
+            for (int i = 1; i < tone.length / 2; i++) {
+                if (sample(tone, i - 1) < 0 && sample(tone, i) >= 0) rising++;
+            }
+            // One rising crossing per period; the first period starts at sample 0 and isn't counted
+            assertTrue(Math.abs(rising - c[0]) <= 1, c[0] + " Hz gave " + rising + " crossings");
+        }
+    }
+
+    @Test
+    void sameChannelCountIsReturnedUnchanged() {
+        byte[] pcm = {1, 2, 3, 4};
+        assertSame(pcm, AudioStreamer.convertChannels(pcm, 2, 2));
+    }
+
+    @Test
+    void monoIsUpmixedByDuplicatingEachSample() {
+        byte[] mono = {1, 2, 3, 4};
+        assertArrayEquals(new byte[] {1, 2, 1, 2, 3, 4, 3, 4}, AudioStreamer.convertChannels(mono, 1, 2));
+    }
+
+    @Test
+    void stereoIsDownmixedByAveragingLeftAndRight() {
+        // Frames (1000, 3000) and (-200, -400), little-endian
+        byte[] stereo = {(byte) 0xE8, 0x03, (byte) 0xB8, 0x0B, 0x38, (byte) 0xFF, 0x70, (byte) 0xFE};
+        byte[] mono = AudioStreamer.convertChannels(stereo, 2, 1);
+        assertEquals(4, mono.length);
+        assertEquals(2000, sample(mono, 0));
+        assertEquals(-300, sample(mono, 1));
+    }
+
+    @Test
+    void unsupportedConversionLeavesTheDataAlone() {
+        byte[] pcm = {1, 2};
+        assertSame(pcm, AudioStreamer.convertChannels(pcm, 1, 6));
+    }
+
+    @Test
+    void chunkAtTheSizeLimitIsAcceptedAndOneByteMoreIsNot() {
+        assertTrue(AudioStreamer.isAcceptableChunk(4096, 1, 4096));
+        assertFalse(AudioStreamer.isAcceptableChunk(4097, 1, 4096));
+        assertTrue(AudioStreamer.isAcceptableChunk(0, 1, 4096));
+    }
+
+    @Test
+    void chunkChannelsMustBeConvertible() {
+        assertTrue(AudioStreamer.isAcceptableChunk(100, 0, 4096)); // unset, treated as mono
+        assertTrue(AudioStreamer.isAcceptableChunk(100, 1, 4096));
+        assertTrue(AudioStreamer.isAcceptableChunk(100, 2, 4096));
+        assertFalse(AudioStreamer.isAcceptableChunk(100, 3, 4096));
+        assertFalse(AudioStreamer.isAcceptableChunk(100, -1, 4096));
+    }
+
+    @Test
+    void delayLineShiftsTheStreamWithoutChangingChunkSizes() {
+        AudioStreamer.DelayLine delay = new AudioStreamer.DelayLine(4);
+        assertArrayEquals(new byte[] {0, 0, 0}, delay.process(new byte[] {1, 2, 3}));
+        assertArrayEquals(new byte[] {0, 1, 2, 3, 4}, delay.process(new byte[] {4, 5, 6, 7, 8}));
+        assertArrayEquals(new byte[] {5, 6}, delay.process(new byte[] {9, 10}));
+    }
+
+    @Test
+    void zeroDelayPassesChunksThrough() {
+        byte[] pcm = {1, 2};
+        assertSame(pcm, new AudioStreamer.DelayLine(0).process(pcm));
+    }
+}
@@ -0,0 +1,207 @@
+package com.conference.client;
+
+import com.conference.grpc.Command;
+import com.conference.grpc.ConferenceData;
+import com.conference.grpc.RoomInfo;
+import io.grpc.stub.StreamObserver;
+import org.junit.jupiter.api.Test;
+
+import java.io.ByteArrayInputStream;
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.ZoneOffset;
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.atomic.AtomicBoolean;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class ChatClientTest {
+
+    private static final Clock CLOCK = Clock.fixed(Instant.parse("2024-03-01T12:00:00Z"), ZoneOffset.UTC);
+
+    private static ChatClient newClient() {
+        return new ChatClient("localhost", 50051, ClientOptions.parse(new String[] {"--quiet"}),
+                new ConsoleInput(new ByteArrayInputStream(new byte[0])), CLOCK);
+    }
+
+    private static ConferenceData command(String type, String value) {
+        return ConferenceData.newBuilder().setSender("server").setRoomId("lobby")
+                .setCommand(Command.newBuilder().setType(type).setValue(value).build()).build();
+    }
+
+    private static List<String> recordEvents(ChatClient client) {
+        List<String> events = new ArrayList<>();
+        client.addEventListener(new ClientEventListener() {
+            @Override
+            public void onConnected(String sender, String roomId) { events.add("connected " + sender + "@" + roomId); }
+            @Override
+            public void onUserJoined(String name) { events.add("joined " + name); }
+            @Override
+            public void onUserLeft(String name) { events.add("left " + name); }
+        });
+        return events;
+    }
+
+    @Test
+    void rosterEventsBeforeWelcomeComeAfterOnConnected() {
+        ChatClient client = newClient();
+        List<String> events = recordEvents(client);
+        client.beginSession("alice", "lobby");
+        StreamObserver<ConferenceData> observer = client.newResponseObserver(new AtomicBoolean(), new CountDownLatch(1));
+
+        observer.onNext(command("USER_JOINED", "bob"));
+        assertTrue(events.isEmpty());
+        observer.onNext(command("WELCOME", ""));
+        observer.onNext(command("USER_LEFT", "bob"));
+
+        assertEquals(List.of("connected alice@lobby", "joined bob", "left bob"), events);
+    }
+
+    @Test
+    void rejectedJoinReportsNoRosterEvents() {
+        ChatClient client = newClient();
+        List<String> events = recordEvents(client);
+        client.beginSession("alice", "lobby");
+        StreamObserver<ConferenceData> observer = client.newResponseObserver(new AtomicBoolean(), new CountDownLatch(1));
+
+        observer.onNext(command("USER_JOINED", "bob"));
+        observer.onNext(command("ROOM_FULL", ""));
+
+        assertTrue(events.isEmpty());
+    }
+
+    @Test
+    void roomInfoShowsTheCreationTimeInTheClockZone() {
+        RoomInfo info = RoomInfo.newBuilder().setRoomId("lobby").setMemberCount(3)
+                .setCreatedAt(Instant.parse("2024-03-01T12:00:00Z").getEpochSecond()).build();
+        assertTrue(ChatClient.formatRoomInfo(info, ZoneOffset.UTC).contains("2024-03-01 12:00"));
+        String shifted = ChatClient.formatRoomInfo(info, ZoneOffset.ofHours(-3));
+        assertTrue(shifted.contains("2024-03-01 09:00"), shifted);
+        assertTrue(shifted.contains("lobby"), shifted);
+    }
+
+    @Test
+    void connectionBannerForIpv4() throws UnknownHostException {
+        InetAddress address = InetAddress.getByName("127.0.0.1");
+        assertEquals(I18n.t("banner.line", "localhost:50051", "127.0.0.1:50051 IPv4", I18n.t("tls.off")),
+                ChatClient.formatConnectionBanner("localhost", 50051, address, false));
+    }
+
+    @Test
+    void connectionBannerForIpv6() throws UnknownHostException {
+        InetAddress address = InetAddress.getByName("::1");
+        String banner = ChatClient.formatConnectionBanner("::1", 50051, address, true);
+        assertEquals(I18n.t("banner.line", "[::1]:50051", "[0:0:0:0:0:0:0:1]:50051 IPv6", I18n.t("tls.on")), banner);
+    }
+
+    @Test
+    void connectionBannerWithoutAddress() {
+        String banner = ChatClient.formatConnectionBanner("chat.example", 7000, null, false);
+        assertEquals(I18n.t("banner.line", "chat.example:7000", I18n.t("banner.unresolved"), I18n.t("tls.off")), banner);
+    }
+
+    private static String whoamiAudio(boolean mic, boolean speakers, boolean monitoring, boolean audioAllowed) {
+        String whoami = ChatClient.formatWhoami("alice", "lobby", "localhost", 50051, false, "abc", mic, speakers, monitoring, audioAllowed);
+        return whoami.substring(whoami.lastIndexOf('\n') + 1);
+    }
+
+    @Test
+    void whoamiShowsIdentityAndServer() {
+        assertEquals(I18n.t("whoami.body", "alice", "lobby", "localhost:50051", I18n.t("tls.off"), "abc",
+                        I18n.t("whoami.mic_off") + I18n.t("whoami.speakers_off")),
+                ChatClient.formatWhoami("alice", "lobby", "localhost", 50051, false, "abc", false, false, false, true));
+        String ipv6 = ChatClient.formatWhoami("alice", "lobby", "::1", 6000, true, "abc", false, false, false, true);
+        assertTrue(ipv6.contains("[::1]:6000 (TLS " + I18n.t("tls.on") + ")"), ipv6);
+    }
+
+    @Test
+    void whoamiShowsTheAudioState() {
+        assertTrue(whoamiAudio(true, true, false, true).endsWith(I18n.t("whoami.mic_on") + I18n.t("whoami.speakers_on")));
+        assertTrue(whoamiAudio(false, true, false, true).endsWith(I18n.t("whoami.mic_off") + I18n.t("whoami.speakers_on")));
+        assertTrue(whoamiAudio(true, true, true, true).endsWith(
+                I18n.t("whoami.mic_on") + I18n.t("whoami.speakers_on") + I18n.t("whoami.monitor")));
+        assertTrue(whoamiAudio(true, true, true, false).endsWith(I18n.t("whoami.audio_blocked")));
+    }
+
+    @Test
+    void displayNameOfEmptyNameIsAnonymous() {
+        assertEquals(I18n.t("display.anonymous"), ChatClient.displayName(""));
+        assertEquals(I18n.t("display.anonymous"), ChatClient.displayName("  \u001B[31m\u0007 "));
+        assertEquals("bob", ChatClient.displayName(" bob "));
+    }
+
+    @Test
+    void displayNameTruncatesOverlongNames() {
+        String max = "a".repeat(ChatClient.MAX_DISPLAY_NAME);
+        assertEquals(max, ChatClient.displayName(max));
+        String shown = ChatClient.displayName(max + "bcd");
+        assertEquals("a".repeat(ChatClient.MAX_DISPLAY_NAME - 1) + "\u2026", shown);
+        assertEquals(ChatClient.MAX_DISPLAY_NAME, shown.length());
+    }
+
+    @Test
+    void validTimestampReplacesMissingAndImplausibleValues() {
+        long now = CLOCK.instant().getEpochSecond();
+        assertEquals(now, ChatClient.validTimestamp(0, now));
+        assertEquals(now - 3600, ChatClient.validTimestamp(now - 3600, now));
+        assertEquals(946684800L, ChatClient.validTimestamp(946684800L, now));
+        assertEquals(now, ChatClient.validTimestamp(946684799L, now));
+        assertEquals(now + 24 * 3600, ChatClient.validTimestamp(now + 24 * 3600, now));
+        assertEquals(now, ChatClient.validTimestamp(now + 24 * 3600 + 1, now));
+    }
+
+    @Test
+    void quoteKeepsShortMessagesWhole() {
+        MessageHistory.Entry entry = new MessageHistory.Entry("bob", "line one\nline two", 0, "t1");
+        assertEquals("> bob: line one line two", ChatClient.formatQuote(entry));
+    }
+
+    @Test
+    void quoteTruncatesAtEightyCharacters() {
+        String exact = "x".repeat(ChatClient.MAX_QUOTE_LENGTH);
+        assertEquals("> bob: " + exact, ChatClient.formatQuote(new MessageHistory.Entry("bob", exact, 0, "t1")));
+        assertEquals("> bob: " + exact + "\u2026", ChatClient.formatQuote(new MessageHistory.Entry("bob", exact + "yz", 0, "t2")));
+    }
+
+    private static int vuLevel(double rms) {
+        String meter = ChatClient.formatVuMeter("bob", rms);
+        assertTrue(meter.endsWith("] bob"), meter);
+        String bar = meter.substring(meter.indexOf('[') + 1, meter.indexOf(']'));
+        assertEquals(ChatClient.VU_METER_WIDTH, bar.length());
+        return bar.replace(" ", "").length();
+    }
+
+    @Test
+    void vuMeterScalesFromMinusSixtyToZeroDecibels() {
+        assertEquals(0, vuLevel(0));
+        assertEquals(0, vuLevel(Short.MAX_VALUE * Math.pow(10, -70 / 20.0)));
+        assertEquals(ChatClient.VU_METER_WIDTH / 2, vuLevel(Short.MAX_VALUE * Math.pow(10, -30 / 20.0)));
+        assertEquals(ChatClient.VU_METER_WIDTH, vuLevel(Short.MAX_VALUE));
+        assertEquals(ChatClient.VU_METER_WIDTH, vuLevel(Short.MAX_VALUE * 2.0));
+    }
+
+    @Test
+    void nudgesFromOneSenderAreThrottled() {
+        Map<String, Long> lastNudge = new HashMap<>();
+        assertTrue(ChatClient.acceptNudge(lastNudge, "bob", 1000));
+        assertFalse(ChatClient.acceptNudge(lastNudge, "bob", 1000 + ChatClient.NUDGE_COOLDOWN_SECONDS - 1));
+        // The dropped nudge did not restart the wait
+        assertTrue(ChatClient.acceptNudge(lastNudge, "bob", 1000 + ChatClient.NUDGE_COOLDOWN_SECONDS));
+        assertFalse(ChatClient.acceptNudge(lastNudge, "bob", 1000 + ChatClient.NUDGE_COOLDOWN_SECONDS + 5));
+    }
+
+    @Test
+    void nudgeSendersAreThrottledIndependently() {
+        Map<String, Long> lastNudge = new HashMap<>();
+        assertTrue(ChatClient.acceptNudge(lastNudge, "bob", 1000));
+        assertTrue(ChatClient.acceptNudge(lastNudge, "carol", 1001));
+        assertFalse(ChatClient.acceptNudge(lastNudge, "bob", 1002));
+        assertFalse(ChatClient.acceptNudge(lastNudge, "carol", 1002));
+    }
+}
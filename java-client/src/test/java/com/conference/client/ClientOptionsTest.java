@@ -0,0 +1,118 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class ClientOptionsTest {
+
+    @Test
+    void positionalNameOnly() {
+        ClientOptions options = ClientOptions.parse(new String[]{"--server", "h:1", "alice"});
+        assertEquals("alice", options.getName());
+        assertEquals("h", options.getHost());
+    }
+
+    @Test
+    void positionalNameAndRoom() {
+        ClientOptions options = ClientOptions.parse(new String[]{"alice", "room42", "--plain"});
+        assertEquals("alice", options.getName());
+        assertEquals("room42", options.getRoom());
+    }
+
+    @Test
+    void positionalNameWinsOverInviteName() {
+        String link = "elo330://chat.example.org:6000/sala42?name=alice";
+        for (String[] args : new String[][]{{"bob", link}, {link, "bob"}}) {
+            ClientOptions options = ClientOptions.parse(args);
+            assertEquals("bob", options.getName());
+            assertEquals("sala42", options.getRoom());
+            assertEquals("chat.example.org", options.getHost());
+            assertEquals(6000, options.getPort());
+        }
+        assertEquals("alice", ClientOptions.parse(new String[]{link}).getName());
+    }
+
+    @Test
+    void positionalRoomConflictsWithInvite() {
+        assertThrows(IllegalArgumentException.class,
+                () -> ClientOptions.parse(new String[]{"bob", "room42", "elo330://h/sala42"}));
+    }
+
+    @Test
+    void chatRoomIsUsedWhenSet() {
+        assertEquals("sala42", ClientOptions.defaultRoom("sala42", false, () -> "lab-pc07"));
+        assertEquals("sala42", ClientOptions.defaultRoom("  sala42 ", false, () -> "lab-pc07"));
+    }
+
+    @Test
+    void noRoomWithoutChatRoomOrHostnameFlag() {
+        assertNull(ClientOptions.defaultRoom(null, false, () -> "lab-pc07"));
+        assertNull(ClientOptions.defaultRoom("   ", false, () -> "lab-pc07"));
+    }
+
+    @Test
+    void invalidChatRoomIsRejected() {
+        assertThrows(IllegalArgumentException.class, () -> ClientOptions.defaultRoom("dos salas", false, () -> "h"));
+        assertThrows(IllegalArgumentException.class, () -> ClientOptions.defaultRoom("sa\u0007la", false, () -> "h"));
+    }
+
+    @Test
+    void hostnameFlagWinsOverChatRoom() {
+        assertEquals("lab-pc07", ClientOptions.defaultRoom("sala42", true, () -> "LAB-PC07.elo.local"));
+    }
+
+    @Test
+    void unusableHostnameIsAnError() {
+        assertThrows(IllegalArgumentException.class, () -> ClientOptions.defaultRoom(null, true, () -> null));
+        assertThrows(IllegalArgumentException.class, () -> ClientOptions.defaultRoom(null, true, () -> "ñ.local"));
+    }
+
+    @Test
+    void roomFromHostnameKeepsTheFirstLabelsSafeCharacters() {
+        assertEquals("lab-pc07", ClientOptions.roomFromHostname("LAB-PC07.elo.local"));
+        assertEquals("my_box", ClientOptions.roomFromHostname(" My_Box "));
+        assertEquals("pc1", ClientOptions.roomFromHostname("pc#1"));
+        assertNull(ClientOptions.roomFromHostname(".local"));
+    }
+
+    // Name and room are given so CHAT_ROOM in the environment does not matter.
+    private static ClientOptions withServer(String server) {
+        return ClientOptions.parse(new String[] {"--server", server, "alice", "lobby"});
+    }
+
+    @Test
+    void serverTakesHostAndOptionalPort() {
+        ClientOptions options = withServer("chat.example.org:6000");
+        assertEquals("chat.example.org", options.getHost());
+        assertEquals(6000, options.getPort());
+        assertEquals(ClientOptions.DEFAULT_PORT, withServer("localhost").getPort());
+    }
+
+    @Test
+    void serverAcceptsBracketedIpv6() {
+        ClientOptions options = withServer("[::1]:6000");
+        assertEquals("::1", options.getHost());
+        assertEquals(6000, options.getPort());
+        assertEquals("fe80::1", withServer("[fe80::1]").getHost());
+        assertEquals(ClientOptions.DEFAULT_PORT, withServer("[fe80::1]").getPort());
+    }
+
+    @Test
+    void serverRejectsBadValues() {
+        for (String server : new String[] {"::1", "h:0", "h:65536", "h:-1", "h:abc", ":6000", "[::1", "[::1]6000", "[]:6000"}) {
+            assertThrows(IllegalArgumentException.class, () -> withServer(server), server);
+        }
+    }
+
+    @Test
+    void formatServerBracketsIpv6() {
+        assertEquals("localhost:50051", ClientOptions.formatServer("localhost", 50051));
+        assertEquals("[::1]:6000", ClientOptions.formatServer("::1", 6000));
+        ClientOptions options = withServer(ClientOptions.formatServer("fe80::1", 6000));
+        assertEquals("fe80::1", options.getHost());
+        assertEquals(6000, options.getPort());
+    }
+}
@@ -0,0 +1,102 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertDoesNotThrow;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class CommandAliasesTest {
+
+    @Test
+    void builtInAliasesExpand() {
+        CommandAliases aliases = new CommandAliases();
+        assertEquals("/quit", aliases.expand("/q"));
+        assertEquals("/mic on", aliases.expand("/m"));
+        assertEquals("/reply 2 de acuerdo", aliases.expand("/r 2 de acuerdo"));
+    }
+
+    @Test
+    void nonAliasesAreLeftAlone() {
+        CommandAliases aliases = new CommandAliases();
+        assertEquals("/quit", aliases.expand("/quit"));
+        assertEquals("/qq", aliases.expand("/qq"));
+        assertEquals("hola", aliases.expand("hola"));
+    }
+
+    @Test
+    void aliasNamesAreCaseInsensitive() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/W=/whoami");
+        assertEquals("/whoami", aliases.expand("/w"));
+        assertEquals("/whoami", aliases.expand("/W"));
+    }
+
+    @Test
+    void aliasesCanChainAndKeepArguments() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/mm=/m");
+        aliases.define("/s=/scroll up");
+        aliases.validate();
+        assertEquals("/mic on", aliases.expand("/mm"));
+        assertEquals("/scroll up", aliases.expand("/s"));
+        assertEquals("/reply 1 ok", aliases.expand("/r 1 ok"));
+    }
+
+    @Test
+    void userAliasReplacesABuiltIn() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/q=/quote");
+        assertEquals("/quote 1", aliases.expand("/q 1"));
+    }
+
+    @Test
+    void malformedDefinitionsAreRejected() {
+        CommandAliases aliases = new CommandAliases();
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("/x"));
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("x=/quit"));
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("/=/quit"));
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("/a b=/quit"));
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("/x=quit"));
+    }
+
+    @Test
+    void realCommandsCannotBeRedefined() {
+        assertThrows(IllegalArgumentException.class, () -> new CommandAliases().define("/quit=/help"));
+    }
+
+    @Test
+    void aliasDefinedTwiceIsRejected() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/x=/help");
+        assertThrows(IllegalArgumentException.class, () -> aliases.define("/x=/stats"));
+    }
+
+    @Test
+    void validateRejectsUnknownTargets() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/x=/nope");
+        assertThrows(IllegalArgumentException.class, aliases::validate);
+    }
+
+    @Test
+    void validateRejectsCycles() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/a=/b");
+        aliases.define("/b=/a");
+        assertThrows(IllegalArgumentException.class, aliases::validate);
+    }
+
+    @Test
+    void expandStopsOnACycle() {
+        CommandAliases aliases = new CommandAliases();
+        aliases.define("/a=/b");
+        aliases.define("/b=/a");
+        assertDoesNotThrow(() -> aliases.expand("/a"));
+    }
+
+    @Test
+    void builtInsAreValid() {
+        assertDoesNotThrow(new CommandAliases()::validate);
+    }
+}
@@ -0,0 +1,50 @@
+package com.conference.client;
+
+import com.conference.grpc.Command;
+import com.conference.grpc.ConferenceData;
+import io.grpc.stub.StreamObserver;
+import org.junit.jupiter.api.Test;
+
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.atomic.AtomicReference;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class ConnectivityCheckTest {
+
+    private static ConferenceData command(String type, String value) {
+        return ConferenceData.newBuilder().setSender("server")
+                .setCommand(Command.newBuilder().setType(type).setValue(value).build()).build();
+    }
+
+    @Test
+    void welcomeSucceeds() {
+        CountDownLatch latch = new CountDownLatch(1);
+        AtomicReference<String> failure = new AtomicReference<>();
+        StreamObserver<ConferenceData> observer = ConnectivityCheck.joinObserver(latch, failure);
+        observer.onNext(command("WELCOME", ""));
+        observer.onCompleted();
+        assertEquals(0, latch.getCount());
+        assertNull(failure.get());
+    }
+
+    @Test
+    void streamClosedBeforeWelcomeIsAFailure() {
+        CountDownLatch latch = new CountDownLatch(1);
+        AtomicReference<String> failure = new AtomicReference<>();
+        ConnectivityCheck.joinObserver(latch, failure).onCompleted();
+        assertEquals(0, latch.getCount());
+        assertEquals(I18n.t("check.closed"), failure.get());
+    }
+
+    @Test
+    void errorCommandIsReportedAsTheFailure() {
+        CountDownLatch latch = new CountDownLatch(1);
+        AtomicReference<String> failure = new AtomicReference<>();
+        StreamObserver<ConferenceData> observer = ConnectivityCheck.joinObserver(latch, failure);
+        observer.onNext(command("ERROR", "sala llena"));
+        observer.onCompleted();
+        assertEquals(0, latch.getCount());
+        assertEquals("sala llena", failure.get());
+    }
+}
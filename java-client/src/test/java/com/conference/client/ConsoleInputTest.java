@@ -0,0 +1,61 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.ByteArrayInputStream;
+import java.io.IOException;
+import java.io.PipedInputStream;
+import java.io.PipedOutputStream;
+import java.nio.charset.StandardCharsets;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class ConsoleInputTest {
+
+    private static ConsoleInput input(String text) {
+        return new ConsoleInput(new ByteArrayInputStream(text.getBytes(StandardCharsets.UTF_8)));
+    }
+
+    @Test
+    void linesAreReadInOrder() throws InterruptedException {
+        ConsoleInput input = input("hola\n/who\r\nchao\n");
+        assertEquals("hola", input.readLine());
+        assertEquals("/who", input.readLine());
+        assertEquals("chao", input.readLine());
+    }
+
+    @Test
+    void endOfInputKeepsReturningNull() throws InterruptedException {
+        ConsoleInput input = input("única");
+        assertEquals("única", input.readLine());
+        assertNull(input.readLine());
+        assertNull(input.readLine());
+        assertNull(input.readLine());
+    }
+
+    @Test
+    void invalidUtf8BecomesReplacementCharacters() throws InterruptedException {
+        // A stray continuation byte, a truncated two-byte sequence, then a valid line
+        byte[] bytes = {'a', (byte) 0x80, 'b', '\n', 'c', (byte) 0xC3, '\n', 'o', 'k', '\n'};
+        ConsoleInput input = new ConsoleInput(new ByteArrayInputStream(bytes));
+        assertEquals("a\uFFFDb", input.readLine());
+        assertEquals("c\uFFFD", input.readLine());
+        assertEquals("ok", input.readLine());
+        assertNull(input.readLine());
+    }
+
+    @Test
+    void fedLinesAreReadLikeTypedOnes() throws InterruptedException, IOException {
+        // Stdin that stays open and silent, so only fed lines arrive
+        PipedOutputStream stdin = new PipedOutputStream();
+        ConsoleInput input = new ConsoleInput(new PipedInputStream(stdin));
+        try {
+            input.feed("/mute");
+            input.feed("hola");
+            assertEquals("/mute", input.readLine());
+            assertEquals("hola", input.readLine());
+        } finally {
+            stdin.close();
+        }
+    }
+}
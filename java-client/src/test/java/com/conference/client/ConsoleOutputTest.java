@@ -0,0 +1,144 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.ByteArrayOutputStream;
+import java.io.PrintStream;
+import java.nio.charset.StandardCharsets;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+
+class ConsoleOutputTest {
+
+    // Runs the writes in the given mode with stdout captured; the previous mode is restored.
+    private static String capture(boolean plain, boolean noRedraw, Runnable writes) {
+        PrintStream stdout = System.out;
+        boolean wasPlain = ConsoleOutput.isPlain();
+        boolean wasNoRedraw = ConsoleOutput.isNoRedraw();
+        ByteArrayOutputStream captured = new ByteArrayOutputStream();
+        try {
+            System.setOut(new PrintStream(captured, true, StandardCharsets.UTF_8));
+            ConsoleOutput.setPlain(plain);
+            ConsoleOutput.setNoRedraw(noRedraw);
+            writes.run();
+        } finally {
+            ConsoleOutput.flush();
+            System.setOut(stdout);
+            ConsoleOutput.setPlain(wasPlain);
+            ConsoleOutput.setNoRedraw(wasNoRedraw);
+        }
+        return new String(captured.toByteArray(), StandardCharsets.UTF_8).replace(System.lineSeparator(), "\n");
+    }
+
+    @Test
+    void plainModeWritesOnlyTheLines() {
+        String output = capture(true, false, () -> {
+            ConsoleOutput.printPrompt("[12:00] alice: ");
+            ConsoleOutput.printLine(ConsoleOutput.highlight("@alice hola"));
+            ConsoleOutput.printProgress("📻 [####    ] bob");
+            ConsoleOutput.bell();
+            ConsoleOutput.clearScreen();
+            ConsoleOutput.printLine("adiós");
+            ConsoleOutput.printPrompt("[12:01] alice: ");
+            ConsoleOutput.endProgress();
+        });
+        assertEquals("@alice hola\nadiós\n", output);
+    }
+
+    @Test
+    void noRedrawPrintsThePromptOncePerEnteredLine() {
+        String output = capture(false, true, () -> {
+            ConsoleOutput.lineEntered();
+            ConsoleOutput.printPrompt("p1> ");
+            ConsoleOutput.printLine(ConsoleOutput.highlight("uno"));
+            ConsoleOutput.printPrompt("p1> ");
+            ConsoleOutput.printLine("dos");
+            ConsoleOutput.printPrompt("p1> ");
+            ConsoleOutput.lineEntered();
+            ConsoleOutput.printPrompt("p2> ");
+            ConsoleOutput.printPrompt("p2> ");
+        });
+        assertEquals("p1> uno\ndos\np2> ", output);
+    }
+
+    @Test
+    void noRedrawWritesNoEscapeSequences() {
+        String output = capture(false, true, () -> {
+            ConsoleOutput.lineEntered();
+            ConsoleOutput.printPrompt("p> ");
+            ConsoleOutput.printLine(ConsoleOutput.highlight("@alice hola"));
+            ConsoleOutput.printProgress("📻 [##  ] bob");
+            ConsoleOutput.clearScreen();
+            ConsoleOutput.endProgress();
+        });
+        assertFalse(output.contains("\u001b"), output);
+        assertEquals("p> @alice hola\n", output);
+    }
+
+    @Test
+    void coalescedBatchIsDrawnWithOneClearAndTheLatestPrompt() {
+        String output = capture(false, false, () -> {
+            ConsoleOutput.setCoalesceMillis(60_000);
+            try {
+                ConsoleOutput.printLine("a");
+                ConsoleOutput.printPrompt("p1> ");
+                ConsoleOutput.printLine("b");
+                ConsoleOutput.printPrompt("p2> ");
+                ConsoleOutput.flush();
+            } finally {
+                ConsoleOutput.setCoalesceMillis(0);
+            }
+        });
+        assertEquals("\r\u001b[2Ka\nb\np2> ", output);
+    }
+
+    @Test
+    void plainModeDisablesStyling() {
+        capture(true, false, () -> assertFalse(ConsoleOutput.usesAnsi()));
+        capture(true, false, () -> assertEquals("x", ConsoleOutput.highlight("x")));
+    }
+
+    @Test
+    void plainTextIsUnchanged() {
+        assertEquals("alice_01 ñandú", ConsoleOutput.sanitize("alice_01 ñandú", 100));
+    }
+
+    @Test
+    void csiSequencesAreDroppedWhole() {
+        assertEquals("redtext", ConsoleOutput.sanitize("\u001b[31mred\u001b[0mtext", 100));
+        assertEquals("ab", ConsoleOutput.sanitize("a\u001b[2Kb", 100));
+        assertEquals("x", ConsoleOutput.sanitize("x\u001b[", 100));
+    }
+
+    @Test
+    void controlCharactersAreDropped() {
+        assertEquals("abc", ConsoleOutput.sanitize("a\rb\u0007c\n", 100));
+        assertEquals("]0;title", ConsoleOutput.sanitize("\u001b]0;title\u0007", 100));
+    }
+
+    @Test
+    void bidiOverridesAreDropped() {
+        assertEquals("evil.txt", ConsoleOutput.sanitize("evil\u202E.txt", 100));
+        assertEquals("ab", ConsoleOutput.sanitize("a\u2066\u2069b", 100));
+    }
+
+    @Test
+    void longTextIsCutWithAnEllipsis() {
+        assertEquals("abcd", ConsoleOutput.sanitize("abcd", 4));
+        assertEquals("abc…", ConsoleOutput.sanitize("abcde", 4));
+    }
+
+    @Test
+    void lengthCountsCodePointsAfterFiltering() {
+        assertEquals("😀😀", ConsoleOutput.sanitize("😀😀", 2));
+        assertEquals("😀…", ConsoleOutput.sanitize("😀😀😀", 2));
+        assertEquals("ab", ConsoleOutput.sanitize("\u001b[1ma\u001b[0mb", 2));
+    }
+
+    @Test
+    void sanitizeTextKeepsLineBreaks() {
+        assertEquals("uno\ndos\n", ConsoleOutput.sanitizeText("uno\r\n\u001b[31mdos\u001b[0m\n"));
+        assertEquals("a b", ConsoleOutput.sanitizeText("a\tb"));
+    }
+}
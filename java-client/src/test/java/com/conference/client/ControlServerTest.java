@@ -0,0 +1,63 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+
+class ControlServerTest {
+
+    @Test
+    void sayBecomesAChatLine() {
+        assertEquals(List.of("hola   a todos"), ControlServer.translate("say hola   a todos", true, "alice"));
+    }
+
+    @Test
+    void sayIsRefusedOutsideARoomEmptyOrAsACommand() {
+        assertNull(ControlServer.translate("say hola", false, "alice"));
+        assertNull(ControlServer.translate("say", true, "alice"));
+        assertNull(ControlServer.translate("say /quit", true, "alice"));
+    }
+
+    @Test
+    void micAcceptsOnlyOnAndOff() {
+        assertEquals(List.of("/mic on"), ControlServer.translate("mic on", true, "alice"));
+        assertEquals(List.of("/mic off"), ControlServer.translate(" mic off ", true, "alice"));
+        assertNull(ControlServer.translate("mic calibrate", true, "alice"));
+        assertNull(ControlServer.translate("mic on", false, "alice"));
+    }
+
+    @Test
+    void joinLeavesTheCurrentRoomFirst() {
+        assertEquals(List.of("/leave", "sala2", "alice"), ControlServer.translate("join sala2", true, "alice"));
+        assertEquals(List.of("sala2", "alice"), ControlServer.translate("join sala2", false, "alice"));
+    }
+
+    @Test
+    void joinWithoutAKnownNameLeavesItToStdin() {
+        assertEquals(List.of("sala2"), ControlServer.translate("join sala2", false, null));
+    }
+
+    @Test
+    void joinRejectsMissingOrUnsafeRooms() {
+        assertNull(ControlServer.translate("join", true, "alice"));
+        assertNull(ControlServer.translate("join dos salas", true, "alice"));
+        assertNull(ControlServer.translate("join QUIT", false, "alice"));
+    }
+
+    @Test
+    void quitDependsOnWhereTheClientIs() {
+        assertEquals(List.of("/quit"), ControlServer.translate("quit", true, "alice"));
+        assertEquals(List.of("quit"), ControlServer.translate("quit", false, "alice"));
+        assertNull(ControlServer.translate("quit now", true, "alice"));
+    }
+
+    @Test
+    void unknownCommandsAreRefused() {
+        assertNull(ControlServer.translate("shutdown", true, "alice"));
+        assertNull(ControlServer.translate("", true, "alice"));
+        assertNull(ControlServer.translate("SAY hola", true, "alice"));
+    }
+}
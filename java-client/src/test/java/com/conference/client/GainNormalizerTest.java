@@ -0,0 +1,72 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class GainNormalizerTest {
+
+    // Alternating +amplitude/-amplitude samples: the RMS equals the amplitude
+    private static byte[] chunk(int amplitude) {
+        byte[] pcm = new byte[640];
+        for (int i = 0; i < pcm.length / 2; i++) {
+            int sample = i % 2 == 0 ? amplitude : -amplitude;
+            pcm[2 * i] = (byte) sample;
+            pcm[2 * i + 1] = (byte) (sample >> 8);
+        }
+        return pcm;
+    }
+
+    // Output level over input level once the gain has settled
+    private static double settledGain(GainNormalizer normalizer, String sender, int amplitude) {
+        byte[] out = null;
+        for (int i = 0; i < 1000; i++) out = normalizer.process(sender, chunk(amplitude));
+        return GainNormalizer.rms(out) / amplitude;
+    }
+
+    @Test
+    void quietSenderIsBoostedAndLoudSenderAttenuated() {
+        GainNormalizer normalizer = new GainNormalizer();
+        assertEquals(2.0, settledGain(normalizer, "quiet", 1500), 0.01);
+        assertEquals(0.75, settledGain(normalizer, "loud", 4000), 0.01);
+        assertEquals(1.0, settledGain(normalizer, "steady", (int) GainNormalizer.TARGET_RMS), 0.01);
+    }
+
+    @Test
+    void gainIsClampedToItsBounds() {
+        GainNormalizer normalizer = new GainNormalizer();
+        assertEquals(GainNormalizer.MAX_GAIN, settledGain(normalizer, "whisper", 150), 0.02);
+        assertEquals(GainNormalizer.MIN_GAIN, settledGain(normalizer, "shout", 30000), 0.01);
+    }
+
+    @Test
+    void gainChangesGradually() {
+        GainNormalizer normalizer = new GainNormalizer();
+        byte[] first = normalizer.process("bob", chunk(150));
+        double gain = GainNormalizer.rms(first) / 150;
+        assertTrue(gain > 1.0 && gain < 1.2, "gain after one chunk " + gain);
+    }
+
+    @Test
+    void silenceDoesNotMoveTheGain() {
+        GainNormalizer normalizer = new GainNormalizer();
+        for (int i = 0; i < 500; i++) {
+            assertArrayEquals(chunk(50), normalizer.process("bob", chunk(50)));
+        }
+        // The estimate is still the initial one, so a chunk at the target passes unchanged
+        assertArrayEquals(chunk((int) GainNormalizer.TARGET_RMS), normalizer.process("bob", chunk((int) GainNormalizer.TARGET_RMS)));
+
+        double boosted = settledGain(normalizer, "carol", 1500);
+        byte[] pause = normalizer.process("carol", chunk(50));
+        assertEquals(boosted, GainNormalizer.rms(pause) / 50, 0.05);
+    }
+
+    @Test
+    void forgetResetsTheSender() {
+        GainNormalizer normalizer = new GainNormalizer();
+        settledGain(normalizer, "bob", 1500);
+        normalizer.forget("bob");
+        byte[] pcm = chunk(50);
+        assertArrayEquals(pcm, normalizer.process("bob", pcm));
+    }
+}
@@ -0,0 +1,55 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.Map;
+import java.util.TreeSet;
+import java.util.regex.Matcher;
+import java.util.regex.Pattern;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class I18nTest {
+
+    @Test
+    void catalogsHaveTheSameKeys() {
+        assertEquals(new TreeSet<>(I18n.catalog(I18n.Lang.EN).keySet()), new TreeSet<>(I18n.catalog(I18n.Lang.ES).keySet()));
+    }
+
+    @Test
+    void patternsTakeTheSameArgumentsInBothLanguages() {
+        for (String key : I18n.catalog(I18n.Lang.ES).keySet()) {
+            assertEquals(placeholders(I18n.catalog(I18n.Lang.EN).get(key)), placeholders(I18n.catalog(I18n.Lang.ES).get(key)), key);
+        }
+    }
+
+    @Test
+    void missingKeyFallsBackToEnglish() {
+        assertEquals("Goodbye!", I18n.format(Map.of(), "app.goodbye"));
+        assertEquals("👋 Nudge sent to bob.", I18n.format(Map.of(), "nudge.sent", "bob"));
+        assertEquals("¡Adiós!", I18n.format(I18n.Lang.ES, "app.goodbye"));
+    }
+
+    @Test
+    void unknownKeyIsPrintedAsIs() {
+        assertEquals("no.such.key", I18n.format(I18n.Lang.ES, "no.such.key"));
+        assertEquals("no.such.key", I18n.format(I18n.Lang.EN, "no.such.key", "ignored"));
+    }
+
+    @Test
+    void languageFromEnvironment() {
+        assertEquals(I18n.Lang.EN, I18n.Lang.fromEnvironment("en_US"));
+        assertEquals(I18n.Lang.EN, I18n.Lang.fromEnvironment("en_US.UTF-8"));
+        assertEquals(I18n.Lang.ES, I18n.Lang.fromEnvironment("es_CL.UTF-8"));
+        assertEquals(I18n.Lang.ES, I18n.Lang.fromEnvironment("C"));
+        assertEquals(I18n.Lang.ES, I18n.Lang.fromEnvironment(null));
+    }
+
+    // The conversions of a pattern in order, e.g. "%s:%d" gives "sd".
+    private static String placeholders(String pattern) {
+        StringBuilder conversions = new StringBuilder();
+        Matcher m = Pattern.compile("%[-0-9.]*([a-zA-Z%])").matcher(pattern);
+        while (m.find()) conversions.append(m.group(1));
+        return conversions.toString();
+    }
+}
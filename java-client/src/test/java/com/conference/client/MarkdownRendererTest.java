@@ -0,0 +1,51 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class MarkdownRendererTest {
+
+    private static final String BOLD = "\u001b[1m";
+    private static final String BOLD_OFF = "\u001b[22m";
+    private static final String ITALIC = "\u001b[3m";
+    private static final String ITALIC_OFF = "\u001b[23m";
+    private static final String CODE = "\u001b[36m";
+    private static final String CODE_OFF = "\u001b[39m";
+
+    @Test
+    void plainTextIsUnchanged() {
+        assertEquals("hola mundo", MarkdownRenderer.render("hola mundo"));
+    }
+
+    @Test
+    void boldItalicAndCodeAreStyled() {
+        assertEquals("a " + BOLD + "b" + BOLD_OFF + " c", MarkdownRenderer.render("a *b* c"));
+        assertEquals(ITALIC + "x" + ITALIC_OFF, MarkdownRenderer.render("_x_"));
+        assertEquals("usa " + CODE + "/mic on" + CODE_OFF, MarkdownRenderer.render("usa `/mic on`"));
+    }
+
+    @Test
+    void stylesNestInsideBoldAndItalic() {
+        assertEquals(BOLD + "a " + ITALIC + "b" + ITALIC_OFF + BOLD_OFF, MarkdownRenderer.render("*a _b_*"));
+    }
+
+    @Test
+    void codeSpanIsNotRenderedFurther() {
+        assertEquals(CODE + "*x*" + CODE_OFF, MarkdownRenderer.render("`*x*`"));
+    }
+
+    @Test
+    void unmatchedAndEmptyMarkersArePrintedAsIs() {
+        assertEquals("2 * 3 = 6", MarkdownRenderer.render("2 * 3 = 6"));
+        assertEquals("**", MarkdownRenderer.render("**"));
+        assertEquals("snake_case", MarkdownRenderer.render("snake_case"));
+    }
+
+    @Test
+    void backslashEscapesAMarker() {
+        assertEquals("*no*", MarkdownRenderer.render("\\*no\\*"));
+        assertEquals(BOLD + "a*b" + BOLD_OFF, MarkdownRenderer.render("*a\\*b*"));
+        assertEquals("\\n", MarkdownRenderer.render("\\n"));
+    }
+}
@@ -0,0 +1,26 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class MentionAlertTest {
+
+    @Test
+    void bellModeMatrix() {
+        assertFalse(MentionAlert.rings(MentionAlert.BellMode.OFF, false));
+        assertFalse(MentionAlert.rings(MentionAlert.BellMode.OFF, true));
+        assertFalse(MentionAlert.rings(MentionAlert.BellMode.MENTION, false));
+        assertTrue(MentionAlert.rings(MentionAlert.BellMode.MENTION, true));
+        assertTrue(MentionAlert.rings(MentionAlert.BellMode.ALL, false));
+        assertTrue(MentionAlert.rings(MentionAlert.BellMode.ALL, true));
+    }
+
+    @Test
+    void parseBellMode() {
+        assertEquals(MentionAlert.BellMode.OFF, MentionAlert.BellMode.parse("off"));
+        assertEquals(MentionAlert.BellMode.MENTION, MentionAlert.BellMode.parse("mention"));
+        assertEquals(MentionAlert.BellMode.ALL, MentionAlert.BellMode.parse("all"));
+        assertNull(MentionAlert.BellMode.parse("ALL"));
+    }
+}
@@ -0,0 +1,188 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class MessageHistoryTest {
+
+    private static MessageHistory.Entry message(String sender, String content, String traceId) {
+        return new MessageHistory.Entry(sender, content, 0, traceId);
+    }
+
+    @Test
+    void mentionMatchesCaseInsensitively() {
+        assertTrue(MessageHistory.mentions("hola @Alice, ¿vienes?", "alice"));
+        assertTrue(MessageHistory.mentions("@alice", "alice"));
+    }
+
+    @Test
+    void mentionMustNotBeGluedToOtherWordCharacters() {
+        assertFalse(MessageHistory.mentions("@alicex", "alice"));
+        assertFalse(MessageHistory.mentions("bob@alice", "alice"));
+        assertFalse(MessageHistory.mentions("@@alice", "alice"));
+        assertFalse(MessageHistory.mentions("alice sin arroba", "alice"));
+    }
+
+    @Test
+    void mentionTreatsTheNameLiterally() {
+        assertTrue(MessageHistory.mentions("hola @a.b", "a.b"));
+        assertFalse(MessageHistory.mentions("hola @axb", "a.b"));
+    }
+
+    @Test
+    void emptyNameNeverMatches() {
+        assertFalse(MessageHistory.mentions("@ hola", ""));
+    }
+
+    @Test
+    void mentionsOfListsOnlyMatchingMessages() {
+        MessageHistory history = new MessageHistory(10);
+        history.add(message("bob", "hola @alice", "t1"));
+        history.add(message("carol", "sin mención", "t2"));
+        history.add(message("dave", "@ALICE mira esto", "t3"));
+        List<MessageHistory.Entry> mentions = history.mentionsOf("alice");
+        assertEquals(2, mentions.size());
+        assertEquals("t1", mentions.get(0).getTraceId());
+        assertEquals("t3", mentions.get(1).getTraceId());
+    }
+
+    @Test
+    void numberResolvesToTheNthMostRecentMessage() {
+        MessageHistory history = new MessageHistory(10);
+        history.add(message("a", "uno", "aaaa-1"));
+        history.add(message("b", "dos", "bbbb-2"));
+        history.add(message("c", "tres", "cccc-3"));
+        assertEquals("tres", history.resolve("1").get(0).getContent());
+        assertEquals("uno", history.resolve("3").get(0).getContent());
+        assertTrue(history.resolve("0").isEmpty());
+        assertTrue(history.resolve("4").isEmpty());
+    }
+
+    @Test
+    void traceIdPrefixResolvesEveryMatch() {
+        MessageHistory history = new MessageHistory(10);
+        history.add(message("a", "uno", "ab12-1"));
+        history.add(message("b", "dos", "ab34-2"));
+        history.add(message("c", "tres", "cd56-3"));
+        assertEquals(1, history.resolve("cd").size());
+        assertEquals(2, history.resolve("ab").size());
+        assertTrue(history.resolve("zz").isEmpty());
+    }
+
+    @Test
+    void messagesWithoutTraceIdAreNotResolvedByPrefix() {
+        MessageHistory history = new MessageHistory(10);
+        history.add(message("a", "uno", ""));
+        assertTrue(history.resolve("").isEmpty());
+    }
+
+    @Test
+    void oldestMessagesAreDroppedAtCapacity() {
+        MessageHistory history = new MessageHistory(2);
+        history.add(message("a", "uno", "t1"));
+        history.add(message("b", "dos", "t2"));
+        history.add(message("c", "tres", "t3"));
+        assertEquals(2, history.size());
+        assertNull(history.findByTraceId("t1"));
+        assertEquals("dos", history.resolve("2").get(0).getContent());
+    }
+
+    @Test
+    void systemEntriesAreNeitherResolvedNorMentioned() {
+        MessageHistory history = new MessageHistory(10);
+        history.add(message("bob", "hola", "ab-1"));
+        history.add(new MessageHistory.Entry("Server", "@alice entró a la sala", 0, "ab-2", true));
+        assertEquals("hola", history.resolve("1").get(0).getContent());
+        assertTrue(history.resolve("2").isEmpty());
+        assertEquals(1, history.resolve("ab").size());
+        assertTrue(history.mentionsOf("alice").isEmpty());
+        assertEquals(2, history.snapshot().size());
+    }
+
+    private static MessageHistory filled(int capacity, int count) {
+        MessageHistory history = new MessageHistory(capacity);
+        for (int i = 1; i <= count; i++) history.add(message("a", String.valueOf(i), "t" + i));
+        return history;
+    }
+
+    private static String first(List<MessageHistory.Entry> window) {
+        return window.get(0).getContent();
+    }
+
+    private static String last(List<MessageHistory.Entry> window) {
+        return window.get(window.size() - 1).getContent();
+    }
+
+    @Test
+    void windowStartsAtTheLatestPage() {
+        MessageHistory history = filled(100, 50);
+        List<MessageHistory.Entry> window = history.window();
+        assertEquals(MessageHistory.PAGE, window.size());
+        assertEquals("31", first(window));
+        assertEquals("50", last(window));
+        assertEquals(0, history.getHidden());
+    }
+
+    @Test
+    void scrollUpMovesTheWindowAndBottomReturnsToTheLatest() {
+        MessageHistory history = filled(100, 50);
+        history.scrollUp();
+        assertEquals("11", first(history.window()));
+        assertEquals("30", last(history.window()));
+        assertEquals(20, history.getHidden());
+
+        history.scrollBottom();
+        assertEquals("50", last(history.window()));
+        assertEquals(0, history.getHidden());
+    }
+
+    @Test
+    void scrollStopsAtTheOldestAndNewestPages() {
+        MessageHistory history = filled(100, 50);
+        history.scrollUp();
+        history.scrollUp();
+        history.scrollUp();
+        assertEquals("1", first(history.window()));
+        assertEquals(30, history.getHidden());
+        history.scrollDown();
+        assertEquals("40", last(history.window()));
+        history.scrollDown();
+        history.scrollDown();
+        assertEquals(0, history.getHidden());
+        history.scrollTop();
+        assertEquals("1", first(history.window()));
+    }
+
+    @Test
+    void newMessagesKeepAScrolledWindowInPlace() {
+        MessageHistory history = filled(100, 50);
+        history.scrollUp();
+        history.add(message("b", "51", "t51"));
+        assertEquals("11", first(history.window()));
+        assertEquals(21, history.getHidden());
+    }
+
+    @Test
+    void shortHistoryFitsInOneWindow() {
+        MessageHistory history = filled(100, 5);
+        history.scrollUp();
+        assertEquals(5, history.window().size());
+        assertEquals(0, history.getHidden());
+        history.clear();
+        assertTrue(history.window().isEmpty());
+    }
+
+    @Test
+    void capacityBoundsTheScrollableHistory() {
+        MessageHistory history = filled(30, 45);
+        assertEquals(30, history.size());
+        history.scrollTop();
+        assertEquals("16", first(history.window()));
+    }
+}
@@ -0,0 +1,84 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+
+class MicCalibrationTest {
+
+    @Test
+    void gainBringsSpeechToTheTargetLevel() {
+        assertEquals(2.0, MicCalibration.computeGain(100, 1500), 1e-9);
+        assertEquals(1.0, MicCalibration.computeGain(0, MicCalibration.TARGET_RMS), 1e-9);
+    }
+
+    @Test
+    void gainIsClamped() {
+        assertEquals(MicCalibration.MAX_GAIN, MicCalibration.computeGain(10, 100), 1e-9);
+        assertEquals(MicCalibration.MIN_GAIN, MicCalibration.computeGain(10, 30000), 1e-9);
+    }
+
+    @Test
+    void speechThatDoesNotStandOutGivesNoGain() {
+        assertNull(MicCalibration.computeGain(1000, 1500));
+        assertNull(MicCalibration.computeGain(0, 0));
+    }
+
+    @Test
+    void thresholdSitsAQuarterOfTheWayFromNoiseToSpeech() {
+        assertEquals(300.0, MicCalibration.computeThreshold(100, 900), 1e-9);
+    }
+
+    @Test
+    void levelsUseMedianAndNinetiethPercentile() {
+        List<Double> chunks = List.of(5.0, 1.0, 9.0, 3.0, 7.0, 2.0, 8.0, 4.0, 6.0, 10.0);
+        assertEquals(6.0, MicCalibration.noiseLevel(chunks), 1e-9);
+        assertEquals(10.0, MicCalibration.speechLevel(chunks), 1e-9);
+        assertEquals(0.0, MicCalibration.noiseLevel(List.of()), 1e-9);
+    }
+
+    @Test
+    void savedValuesLoadBack() throws IOException {
+        Path file = Files.createTempFile("mic_calibration", ".json");
+        try {
+            MicCalibration calibration = new MicCalibration(file);
+            calibration.save(1.234, 456.789, 100, 2000);
+            assertEquals(1.23, calibration.load(), 1e-9);
+            assertEquals(456.79, calibration.loadThreshold(), 1e-9);
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    @Test
+    void missingOrBrokenFileLoadsNothing() throws IOException {
+        Path file = Files.createTempFile("mic_calibration", ".json");
+        try {
+            Files.write(file, "{\"gain\": ".getBytes(StandardCharsets.UTF_8));
+            assertNull(new MicCalibration(file).load());
+            Files.delete(file);
+            assertNull(new MicCalibration(file).load());
+            assertNull(new MicCalibration(file).loadThreshold());
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    @Test
+    void storedGainIsClampedOnLoad() throws IOException {
+        Path file = Files.createTempFile("mic_calibration", ".json");
+        try {
+            Files.write(file, "{\"gain\": 50}".getBytes(StandardCharsets.UTF_8));
+            assertEquals(MicCalibration.MAX_GAIN, new MicCalibration(file).load(), 1e-9);
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+}
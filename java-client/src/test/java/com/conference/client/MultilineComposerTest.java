@@ -0,0 +1,55 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class MultilineComposerTest {
+
+    @Test
+    void loneDotEndsTheMessage() {
+        MultilineComposer composer = new MultilineComposer();
+        assertFalse(composer.accept("first"));
+        assertFalse(composer.accept("  indented second"));
+        assertTrue(composer.accept(" . "));
+        assertEquals("first\n  indented second", composer.text());
+    }
+
+    @Test
+    void emptyLineEndsTheMessage() {
+        MultilineComposer composer = new MultilineComposer();
+        assertFalse(composer.accept("only line"));
+        assertTrue(composer.accept(""));
+        assertEquals("only line", composer.text());
+    }
+
+    @Test
+    void linesAfterTheTerminatorAreIgnored() {
+        MultilineComposer composer = new MultilineComposer();
+        assertTrue(composer.accept("."));
+        assertTrue(composer.accept("late"));
+        assertTrue(composer.isEmpty());
+        assertEquals("", composer.text());
+    }
+
+    @Test
+    void dotInsideALineIsText() {
+        MultilineComposer composer = new MultilineComposer();
+        assertFalse(composer.accept(".."));
+        assertFalse(composer.accept("end."));
+        assertTrue(composer.accept("   "));
+        assertEquals("..\nend.", composer.text());
+    }
+
+    @Test
+    void continuationLinesAlignUnderTheFirst() {
+        assertEquals("one\n      two\n      three", MultilineComposer.indentContinuation("one\ntwo\nthree", 6));
+        assertEquals("single", MultilineComposer.indentContinuation("single", 6));
+    }
+
+    @Test
+    void carriageReturnsAreDropped() {
+        assertEquals("fake prompt", MultilineComposer.indentContinuation("fake\r prompt", 4));
+        assertEquals("a\n  b", MultilineComposer.indentContinuation("a\r\nb", 2));
+    }
+}
@@ -0,0 +1,80 @@
+package com.conference.client;
+
+import com.conference.grpc.AudioChunk;
+import com.conference.grpc.ChatMessage;
+import com.conference.grpc.Command;
+import com.conference.grpc.ConferenceData;
+import com.google.protobuf.ByteString;
+import io.grpc.stub.StreamObserver;
+import org.junit.jupiter.api.Test;
+
+import java.io.ByteArrayOutputStream;
+import java.io.PrintStream;
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class ProtoDumpTest {
+
+    private static ConferenceData audio(int size) {
+        byte[] pcm = new byte[size];
+        for (int i = 0; i < size; i++) pcm[i] = (byte) i;
+        return ConferenceData.newBuilder().setRoomId("lobby").setSender("bob")
+                .setAudioChunk(AudioChunk.newBuilder().setData(ByteString.copyFrom(pcm)).setChannels(1).build()).build();
+    }
+
+    @Test
+    void chatMessageIsPrintedInTextForm() {
+        ConferenceData data = ConferenceData.newBuilder().setRoomId("lobby").setSender("alice")
+                .setTextMessage(ChatMessage.newBuilder().setContent("hola").setTraceId("a-1").build()).build();
+        assertEquals(">> room_id: \"lobby\" sender: \"alice\" text_message { content: \"hola\" trace_id: \"a-1\" }",
+                ProtoDump.format(">>", data));
+    }
+
+    @Test
+    void commandIsPrintedInTextForm() {
+        ConferenceData data = ConferenceData.newBuilder().setRoomId("lobby").setSender("server")
+                .setCommand(Command.newBuilder().setType("USER_JOINED").setValue("bob").build()).build();
+        assertEquals("<< room_id: \"lobby\" sender: \"server\" command { type: \"USER_JOINED\" value: \"bob\" }",
+                ProtoDump.format("<<", data));
+    }
+
+    @Test
+    void audioPayloadIsSummarizedNotDumped() {
+        ConferenceData data = audio(1000);
+        assertEquals("<< room_id: \"lobby\" sender: \"bob\" audio_chunk { channels: 1 }"
+                + " [audio 1000 bytes: 00 01 02 03 04 05 06 07 ...]", ProtoDump.format("<<", data));
+        assertEquals(1000, data.getAudioChunk().getData().size(), "the traced message is left intact");
+    }
+
+    @Test
+    void shortAudioPayloadIsShownWhole() {
+        assertEquals("<< room_id: \"lobby\" sender: \"bob\" audio_chunk { channels: 1 } [audio 3 bytes: 00 01 02]",
+                ProtoDump.format("<<", audio(3)));
+    }
+
+    @Test
+    void messagesArePrintedAndPassedOn() {
+        List<ConferenceData> received = new ArrayList<>();
+        List<String> ends = new ArrayList<>();
+        ByteArrayOutputStream out = new ByteArrayOutputStream();
+        ProtoDump dump = new ProtoDump(">>", new StreamObserver<ConferenceData>() {
+            @Override
+            public void onNext(ConferenceData value) { received.add(value); }
+            @Override
+            public void onError(Throwable t) { ends.add("error"); }
+            @Override
+            public void onCompleted() { ends.add("completed"); }
+        }, new PrintStream(out, true, StandardCharsets.UTF_8));
+        ConferenceData data = audio(2);
+        dump.onNext(data);
+        dump.onCompleted();
+
+        assertEquals(List.of(data), received);
+        assertEquals(List.of("completed"), ends);
+        assertEquals(ProtoDump.format(">>", data) + System.lineSeparator() + ">> completed" + System.lineSeparator(),
+                new String(out.toByteArray(), StandardCharsets.UTF_8));
+    }
+}
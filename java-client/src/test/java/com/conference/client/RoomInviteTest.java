@@ -0,0 +1,86 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class RoomInviteTest {
+
+    @Test
+    void parsesHostPortRoomAndName() {
+        RoomInvite invite = RoomInvite.parse("elo330://chat.example.org:6000/sala42?name=alice");
+        assertEquals("chat.example.org", invite.getHost());
+        assertEquals(6000, invite.getPort());
+        assertEquals("sala42", invite.getRoom());
+        assertEquals("alice", invite.getName());
+    }
+
+    @Test
+    void portAndNameAreOptional() {
+        RoomInvite invite = RoomInvite.parse("ELO330://localhost/lobby");
+        assertEquals("localhost", invite.getHost());
+        assertEquals(ClientOptions.DEFAULT_PORT, invite.getPort());
+        assertNull(invite.getName());
+        assertNull(RoomInvite.parse("elo330://localhost/lobby?name=").getName());
+    }
+
+    @Test
+    void toUriEncodesAndParseDecodes() {
+        RoomInvite invite = new RoomInvite("localhost", 50051, "sala de música", "josé+ana");
+        String uri = invite.toUri();
+        assertEquals("elo330://localhost:50051/sala%20de%20m%C3%BAsica?name=jos%C3%A9%2Bana", uri);
+        RoomInvite parsed = RoomInvite.parse(uri);
+        assertEquals("sala de música", parsed.getRoom());
+        assertEquals("josé+ana", parsed.getName());
+    }
+
+    @Test
+    void literalPlusIsNotASpace() {
+        assertEquals("a+b", RoomInvite.parse("elo330://h/a+b").getRoom());
+    }
+
+    @Test
+    void toUriWithoutNameHasNoQuery() {
+        assertEquals("elo330://h:1/r", new RoomInvite("h", 1, "r", null).toUri());
+    }
+
+    @Test
+    void ipv6HostsRoundTripInBrackets() {
+        String uri = new RoomInvite("::1", 50051, "lobby", null).toUri();
+        assertEquals("elo330://[::1]:50051/lobby", uri);
+        RoomInvite parsed = RoomInvite.parse(uri);
+        assertEquals("::1", parsed.getHost());
+        assertEquals(50051, parsed.getPort());
+        assertEquals(ClientOptions.DEFAULT_PORT, RoomInvite.parse("elo330://[fe80::1]/lobby").getPort());
+    }
+
+    @Test
+    void isInviteChecksTheScheme() {
+        assertTrue(RoomInvite.isInvite("elo330://h/r"));
+        assertFalse(RoomInvite.isInvite("http://h/r"));
+        assertFalse(RoomInvite.isInvite("alice"));
+    }
+
+    @Test
+    void invalidLinksAreRejected() {
+        for (String uri : new String[] {
+                "http://h/r",
+                "elo330://h",
+                "elo330:///r",
+                "elo330://h/",
+                "elo330://h:abc/r",
+                "elo330://h:0/r",
+                "elo330://h:65536/r",
+                "elo330://:50051/r",
+                "elo330://[::1/r",
+                "elo330://[::1]x/r",
+                "elo330://h/%zz",
+        }) {
+            assertThrows(IllegalArgumentException.class, () -> RoomInvite.parse(uri), uri);
+        }
+    }
+}
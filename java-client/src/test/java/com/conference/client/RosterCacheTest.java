@@ -0,0 +1,129 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.time.Clock;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.ZoneOffset;
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class RosterCacheTest {
+
+    private static final Clock CLOCK = Clock.fixed(Instant.parse("2024-03-01T12:00:00Z"), ZoneOffset.UTC);
+    private static final long NOW = CLOCK.instant().getEpochSecond();
+
+    @Test
+    void saveAndLoadRoundTrip() throws IOException {
+        Path file = Files.createTempFile("roster", ".json");
+        try {
+            RosterCache cache = new RosterCache(file, CLOCK);
+            cache.seen("lobby", "bob");
+            cache.seen("lobby", "carol");
+            cache.seen("music", "dave");
+            cache.save();
+
+            RosterCache loaded = new RosterCache(file, CLOCK);
+            loaded.load();
+            assertEquals(Map.of("bob", NOW, "carol", NOW), loaded.lastSeen("lobby"));
+            assertEquals(Map.of("dave", NOW), loaded.lastSeen("music"));
+            assertEquals(Map.of(), loaded.lastSeen("quiet"));
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    @Test
+    void missingFileLoadsEmpty() throws IOException {
+        Path file = Files.createTempFile("roster", ".json");
+        Files.delete(file);
+        RosterCache cache = new RosterCache(file, CLOCK);
+        cache.load();
+        assertEquals(Map.of(), cache.lastSeen("lobby"));
+    }
+
+    @Test
+    void namesExpireAfterThirtyDays() throws IOException {
+        Path file = Files.createTempFile("roster", ".json");
+        try {
+            RosterCache old = new RosterCache(file, Clock.offset(CLOCK, Duration.ofSeconds(-RosterCache.MAX_AGE_SECONDS)));
+            old.seen("lobby", "bob");
+            old.save();
+            RosterCache older = new RosterCache(file, Clock.offset(CLOCK, Duration.ofSeconds(-RosterCache.MAX_AGE_SECONDS - 1)));
+            older.load();
+            older.seen("lobby", "carol");
+            older.save();
+
+            RosterCache cache = new RosterCache(file, CLOCK);
+            cache.load();
+            assertEquals(Map.of("bob", NOW - RosterCache.MAX_AGE_SECONDS), cache.lastSeen("lobby"));
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    @Test
+    void lastSeenIsMostRecentFirst() throws IOException {
+        Path file = Files.createTempFile("roster", ".json");
+        try {
+            writeRoster(file, Map.of("bob", NOW - 60, "carol", NOW - 10, "dave", NOW - 3600));
+            RosterCache cache = new RosterCache(file, CLOCK);
+            cache.load();
+            assertEquals(List.of("carol", "bob", "dave"), new ArrayList<>(cache.lastSeen("lobby").keySet()));
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    @Test
+    void seenKeepsAtMostOneHundredNamesPerRoom() {
+        RosterCache cache = new RosterCache(CLOCK);
+        for (int i = 0; i < RosterCache.MAX_PER_ROOM + 50; i++) {
+            cache.seen("lobby", "user" + i);
+        }
+        cache.seen("music", "bob");
+        assertEquals(RosterCache.MAX_PER_ROOM, cache.memberCount("lobby"));
+        assertEquals(RosterCache.MAX_PER_ROOM, cache.lastSeen("lobby").size());
+        assertTrue(cache.lastSeen("lobby").containsKey("user" + (RosterCache.MAX_PER_ROOM + 49)));
+        assertEquals(1, cache.memberCount("music"));
+    }
+
+    @Test
+    void loadKeepsTheMostRecentHundred() throws IOException {
+        Path file = Files.createTempFile("roster", ".json");
+        try {
+            Map<String, Long> members = new LinkedHashMap<>();
+            for (int i = 0; i < RosterCache.MAX_PER_ROOM + 20; i++) members.put("user" + i, NOW - i);
+            writeRoster(file, members);
+            RosterCache cache = new RosterCache(file, CLOCK);
+            cache.load();
+            assertEquals(RosterCache.MAX_PER_ROOM, cache.memberCount("lobby"));
+            assertTrue(cache.lastSeen("lobby").containsKey("user0"));
+            assertFalse(cache.lastSeen("lobby").containsKey("user" + RosterCache.MAX_PER_ROOM));
+
+            cache.seen("lobby", "newcomer");
+            assertEquals(RosterCache.MAX_PER_ROOM, cache.memberCount("lobby"));
+            assertTrue(cache.lastSeen("lobby").containsKey("newcomer"));
+            assertFalse(cache.lastSeen("lobby").containsKey("user" + (RosterCache.MAX_PER_ROOM - 1)));
+        } finally {
+            Files.deleteIfExists(file);
+        }
+    }
+
+    private static void writeRoster(Path file, Map<String, Long> members) throws IOException {
+        List<Map<String, String>> rows = new ArrayList<>();
+        for (Map.Entry<String, Long> member : members.entrySet()) {
+            rows.add(Map.of("room_id", "lobby", "name", member.getKey(), "last_seen", Long.toString(member.getValue())));
+        }
+        Files.write(file, SimpleJson.array(rows).getBytes(StandardCharsets.UTF_8));
+    }
+}
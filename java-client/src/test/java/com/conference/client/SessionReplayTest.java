@@ -0,0 +1,93 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.PrintStream;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.time.Clock;
+import java.time.Instant;
+import java.time.ZoneOffset;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class SessionReplayTest {
+
+    private static final long NOON_UTC = Instant.parse("2024-03-01T12:00:00Z").toEpochMilli();
+    private static final Clock CLOCK = Clock.fixed(Instant.ofEpochMilli(NOON_UTC), ZoneOffset.UTC);
+
+    // A recording as --record-session writes it, with a blank line and an event this version does not know
+    private static final List<String> FIXTURE = List.of(
+            "{\"at\":" + NOON_UTC + ",\"event\":\"connected\",\"sender\":\"alice\",\"room_id\":\"lobby\"}",
+            "{\"at\":" + (NOON_UTC + 1000) + ",\"event\":\"user_joined\",\"name\":\"bob\"}",
+            "",
+            "{\"at\":" + (NOON_UTC + 2000) + ",\"event\":\"message_received\",\"sender\":\"bob\",\"content\":\"hola\\ncomo estas\",\"trace_id\":\"b-1\"}",
+            "{\"at\":" + (NOON_UTC + 2500) + ",\"event\":\"reaction\",\"emoji\":\"+1\"}",
+            "{\"at\":" + (NOON_UTC + 3000) + ",\"event\":\"command\",\"line\":\"/mic on\"}",
+            "{\"at\":" + (NOON_UTC + 3000) + ",\"event\":\"audio\",\"transmitting\":\"true\"}",
+            "{\"at\":" + (NOON_UTC + 4000) + ",\"event\":\"message_sent\",\"sender\":\"alice\",\"content\":\"bien\",\"trace_id\":\"a-1\"}",
+            "{\"at\":" + (NOON_UTC + 5000) + ",\"event\":\"disconnected\",\"room_id\":\"lobby\"}");
+
+    private static String replay(List<String> lines, int speed, int expectedExit) throws IOException {
+        Path file = Files.createTempFile("session", ".jsonl");
+        PrintStream stdout = System.out;
+        boolean plain = ConsoleOutput.isPlain();
+        ByteArrayOutputStream captured = new ByteArrayOutputStream();
+        try {
+            Files.write(file, lines, StandardCharsets.UTF_8);
+            System.setOut(new PrintStream(captured, true, "UTF-8"));
+            ConsoleOutput.setPlain(true);
+            assertEquals(expectedExit, SessionReplay.run(file, speed, CLOCK));
+        } finally {
+            System.setOut(stdout);
+            ConsoleOutput.setPlain(plain);
+            Files.deleteIfExists(file);
+        }
+        return new String(captured.toByteArray(), StandardCharsets.UTF_8).replace(System.lineSeparator(), "\n");
+    }
+
+    @Test
+    void replayPrintsEveryKnownEventWithoutPausesAtSpeedZero() throws IOException {
+        long start = System.nanoTime();
+        String output = replay(FIXTURE, 0, 0);
+        assertTrue(System.nanoTime() - start < 1_000_000_000L, "speed 0 must not sleep");
+        String self = I18n.t("self.label") + ": ";
+        assertEquals(String.join("\n",
+                "[12:00:00] " + I18n.t("replay.connected", "alice", "lobby"),
+                "[12:00:01] [SERVER] USER_JOINED: bob",
+                "[12:00:02] bob: hola",
+                "                como estas",
+                "[12:00:03] > /mic on",
+                "[12:00:03] " + I18n.t("replay.mic_on"),
+                "[12:00:04] " + self + "bien",
+                "[12:00:05] " + I18n.t("replay.disconnected", "lobby"),
+                ""), output);
+    }
+
+    @Test
+    void replayWaitsTheGapsDividedBySpeed() throws IOException {
+        long start = System.nanoTime();
+        replay(FIXTURE, 20, 0); // 5 s of recording at 20x
+        long elapsedMillis = (System.nanoTime() - start) / 1_000_000;
+        assertTrue(elapsedMillis >= 250, "elapsed " + elapsedMillis + " ms");
+        assertTrue(elapsedMillis < 2500, "elapsed " + elapsedMillis + " ms");
+    }
+
+    @Test
+    void invalidLineStopsTheReplay() throws IOException {
+        String output = replay(List.of(FIXTURE.get(0), "{not json", FIXTURE.get(1)), 0, 1);
+        assertEquals("[12:00:00] " + I18n.t("replay.connected", "alice", "lobby") + "\n", output);
+    }
+
+    @Test
+    void eventTimeUsesTheGivenZone() {
+        Map<String, String> event = Map.of("event", "user_joined", "name", "bob");
+        assertEquals("[12:00:00] [SERVER] USER_JOINED: bob", SessionReplay.formatEvent(event, NOON_UTC, ZoneOffset.UTC));
+        assertEquals("[09:00:00] [SERVER] USER_JOINED: bob", SessionReplay.formatEvent(event, NOON_UTC, ZoneOffset.ofHours(-3)));
+    }
+}
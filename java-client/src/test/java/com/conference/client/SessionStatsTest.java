@@ -0,0 +1,53 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+class SessionStatsTest {
+
+    @Test
+    void resetClearsTheStatsCounters() {
+        SessionStats stats = new SessionStats();
+        stats.messageSent();
+        stats.messageReceived();
+        stats.messageReceived();
+        stats.audioSent(640, 1);
+        stats.audioReceived(1280, 2);
+        stats.audioRejected();
+        assertEquals(1, stats.getMessagesSent());
+        assertEquals(2, stats.getMessagesReceived());
+        assertEquals(640, stats.getAudioBytesSent());
+        assertEquals(1280, stats.getAudioBytesReceived());
+        assertEquals(1, stats.getAudioChunksRejected());
+
+        stats.reset();
+
+        assertEquals(0, stats.getMessagesSent());
+        assertEquals(0, stats.getMessagesReceived());
+        assertEquals(0, stats.getAudioBytesSent());
+        assertEquals(0, stats.getAudioBytesReceived());
+        assertEquals(0, stats.getAudioChunksRejected());
+    }
+
+    @Test
+    void resetKeepsTheSessionSummary() {
+        SessionStats stats = new SessionStats();
+        stats.messageSent();
+        stats.messageReceived();
+        stats.messageReceived();
+        stats.audioSent(AudioStreamer.SAMPLE_RATE * 2, 1); // one second of 16-bit mono
+        stats.reconnected();
+        stats.connectedFor(61_000);
+        stats.latency(40);
+        String before = stats.formatSummary();
+
+        stats.reset();
+        stats.messageSent();
+
+        String after = stats.formatSummary();
+        assertEquals(before.replace("1 / 2", "2 / 2"), after);
+        assertTrue(after.contains("0:01:01"), after);
+        assertTrue(after.contains(String.format("%.1f s / %.1f s", 1.0, 0.0)), after);
+    }
+}
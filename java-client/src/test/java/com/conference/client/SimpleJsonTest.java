@@ -0,0 +1,83 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class SimpleJsonTest {
+
+    @Test
+    void quoteEscapesSpecialCharacters() {
+        assertEquals("\"a\\\"b\\\\c\\nd\\te\\u0001\"", SimpleJson.quote("a\"b\\c\nd\te\u0001"));
+    }
+
+    @Test
+    void arrayRoundTripsAwkwardStrings() {
+        Map<String, String> first = new LinkedHashMap<>();
+        first.put("sender", "bob \"el grande\"");
+        first.put("content", "línea 1\nlínea 2\t\\fin\r");
+        first.put("control", "\u0007");
+        Map<String, String> second = new LinkedHashMap<>();
+        second.put("note", "");
+        List<Map<String, String>> objects = List.of(first, second);
+        assertEquals(objects, SimpleJson.parseArray(SimpleJson.array(objects)));
+    }
+
+    @Test
+    void numbersAreWrittenBareAndReadBackAsStrings() {
+        Map<String, Object> fields = new LinkedHashMap<>();
+        fields.put("timestamp", 1700000000L);
+        fields.put("gain", 1.5);
+        String json = SimpleJson.object(fields);
+        assertEquals("{\"timestamp\": 1700000000, \"gain\": 1.5}", json);
+        assertEquals(Map.of("timestamp", "1700000000", "gain", "1.5"), SimpleJson.parseObject(json));
+    }
+
+    @Test
+    void emptyArrayAndObjectParse() {
+        assertTrue(SimpleJson.parseArray(" [ ] ").isEmpty());
+        assertTrue(SimpleJson.parseObject("{}").isEmpty());
+    }
+
+    @Test
+    void unicodeEscapeIsDecoded() {
+        assertEquals("ñA", SimpleJson.parseObject("{\"k\": \"\\u00f1\\u0041\"}").get("k"));
+    }
+
+    @Test
+    void malformedInputThrowsIllegalArgument() {
+        for (String json : new String[] {
+                "",
+                "{",
+                "{\"k\"",
+                "{\"k\": \"sin cerrar",
+                "{\"k\" \"v\"}",
+                "{\"k\": \"v\"",
+                "[]",
+        }) {
+            assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject(json), json);
+        }
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseArray("[{\"k\": \"v\"} {\"k\": \"w\"}]"));
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseArray("[{\"k\": \"v\"},"));
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseArray("{}"));
+    }
+
+    @Test
+    void truncatedEscapeThrowsIllegalArgument() {
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject("{\"k\": \"abc\\"));
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject("{\"k\": \"\\u00"));
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject("{\"k\": \"\\u"));
+    }
+
+    @Test
+    void badUnicodeEscapeThrowsIllegalArgument() {
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject("{\"k\": \"\\u00zz\"}"));
+        assertThrows(IllegalArgumentException.class, () -> SimpleJson.parseObject("{\"k\": \"\\u+041\"}"));
+    }
+}
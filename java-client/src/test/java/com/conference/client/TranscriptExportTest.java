@@ -0,0 +1,65 @@
+package com.conference.client;
+
+import org.junit.jupiter.api.Test;
+
+import java.nio.file.Paths;
+import java.time.ZoneOffset;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptExportTest {
+
+    // 2024-01-02 03:04 UTC
+    private static final long TIME = 1704164640L;
+
+    private static final List<MessageHistory.Entry> ENTRIES = List.of(
+            new MessageHistory.Entry("alice", "hola *a todos*", TIME, "t1"),
+            new MessageHistory.Entry("Server", "bob entró a la sala", TIME, "", true),
+            new MessageHistory.Entry("Sistema <b>", "<script>alert('x')</script> & más", TIME, "t2"));
+
+    @Test
+    void markdownHasHeadingAndOneLinePerEntry() {
+        String md = TranscriptExport.toMarkdown("sala_1", ENTRIES, ZoneOffset.UTC);
+        assertEquals("# Sala sala\\_1\n\n"
+                + "- `2024-01-02 03:04` **alice:** hola \\*a todos\\*\n"
+                + "- `2024-01-02 03:04` *bob entró a la sala*\n"
+                + "- `2024-01-02 03:04` **Sistema \\<b\\>:** \\<script\\>alert('x')\\</script\\> \\& más\n", md);
+    }
+
+    @Test
+    void markdownKeepsLineBreaksInsideTheItem() {
+        String md = TranscriptExport.toMarkdown("r", List.of(new MessageHistory.Entry("a", "uno\ndos", TIME, "t")), ZoneOffset.UTC);
+        assertTrue(md.contains("uno  \n  dos\n"), md);
+    }
+
+    @Test
+    void htmlEscapesContentSenderAndRoom() {
+        String html = TranscriptExport.toHtml("<sala>", ENTRIES, ZoneOffset.UTC);
+        assertTrue(html.contains("<title>Sala &lt;sala&gt;</title>"));
+        assertTrue(html.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; más"));
+        assertTrue(html.contains("<b>Sistema &lt;b&gt;:</b>"));
+        assertFalse(html.contains("<script>"));
+    }
+
+    @Test
+    void onlyFlaggedEntriesAreStyledAsSystem() {
+        String html = TranscriptExport.toHtml("r", ENTRIES, ZoneOffset.UTC);
+        assertEquals(1, html.split("class=\"system\"", -1).length - 1);
+        assertTrue(html.contains("<li class=\"system\"><span class=\"time\">2024-01-02 03:04</span> <span class=\"text\">bob entró a la sala</span></li>"));
+    }
+
+    @Test
+    void escapeHtmlCoversEverySpecialCharacter() {
+        assertEquals("&amp;&lt;&gt;&quot;&#39;", TranscriptExport.escapeHtml("&<>\"'"));
+    }
+
+    @Test
+    void unknownExtensionIsRejected() {
+        assertThrows(IllegalArgumentException.class,
+                () -> TranscriptExport.write(Paths.get("transcript.txt"), "r", ENTRIES, ZoneOffset.UTC));
+    }
+}